@@ -14,14 +14,24 @@
 
 use std::time::Duration;
 
+use ai::core::agent::{Agent, AgentConfig};
+use ai::core::game_state_node::GameStateNode;
+use ai::core::selection_algorithm::SelectionAlgorithm;
 use ai::game::agents::AgentName;
+use ai::game::evaluators::TrickEvaluator;
+use ai::nim::nim_agents::NIM_ALPHA_BETA_AGENT;
+use ai::nim::nim_game::NimState;
 use ai::testing::run_matchup;
 use ai::testing::run_matchup::{MatchupArgs, Verbosity};
+use ai::tree_search::alpha_beta::AlphaBetaAlgorithm;
 use criterion::measurement::WallTime;
 use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rules::rounds::new_round;
 
 criterion_main!(benches);
-criterion_group!(benches, uct1);
+criterion_group!(benches, uct1, alpha_beta);
 
 pub fn uct1(c: &mut Criterion) {
     let mut group = start(c, "uct1");
@@ -35,11 +45,36 @@ pub fn uct1(c: &mut Criterion) {
                 matches: 1,
                 verbosity: Verbosity::None,
                 panic_on_search_timeout: false,
+                threads: 1,
+                seed: Some(1),
+                record_dir: None,
+                pbn: None,
             })
         })
     });
 }
 
+pub fn alpha_beta(c: &mut Criterion) {
+    let mut group = start(c, "alpha_beta");
+    let game = new_round::create_play_phase(&mut StdRng::seed_from_u64(1), 3);
+    let algorithm = AlphaBetaAlgorithm { search_depth: 10 };
+    group.bench_function("AlphaBetaDepth10", |b| {
+        b.iter(|| {
+            algorithm.pick_action(
+                AgentConfig::with_deadline(60),
+                &game,
+                &TrickEvaluator,
+                game.current_turn(),
+            )
+        })
+    });
+
+    let nim = NimState::new(6);
+    group.bench_function("NimAlphaBeta", |b| {
+        b.iter(|| NIM_ALPHA_BETA_AGENT.pick_action(AgentConfig::with_deadline(60), &nim))
+    });
+}
+
 fn start<'a>(c: &'a mut Criterion, s: &'static str) -> BenchmarkGroup<'a, WallTime> {
     programs::linkme();
     c.benchmark_group(s)
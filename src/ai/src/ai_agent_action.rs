@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
 use crossbeam::atomic::AtomicCell;
@@ -19,56 +20,81 @@ use data::contract_phase_data::{ContractPhaseAction, ContractPhaseData};
 use data::game_action::GameAction;
 use data::play_phase_data::PlayPhaseData;
 use data::primitive::primitives::PlayerName;
-use rules::rounds::tricks;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use tracing::info;
 
 use crate::core::agent::AgentConfig;
-use crate::game::agents;
 use crate::game::agents::AgentName;
-use crate::testing::run_matchup;
-use crate::testing::run_matchup::Verbosity;
+use crate::game::{agents, bidding};
 
 static AGENT_ACTION: AtomicCell<Option<GameAction>> = AtomicCell::new(None);
 
+/// Incremented whenever pending searches are cancelled. A search only stores
+/// its result if the generation has not changed since it started.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
 /// Removes & returns the next AI agent action to take, if any is available.
 pub fn poll_action() -> Option<GameAction> {
     AGENT_ACTION.take()
 }
 
-pub fn initiate_selection(data: PlayPhaseData) {
-    info!("Starting AI Agent search");
+/// Discards the result of any AI agent search which is currently running or
+/// has finished but not yet been polled.
+pub fn cancel() {
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+    AGENT_ACTION.take();
+}
+
+fn store(generation: u64, action: GameAction) {
+    if GENERATION.load(Ordering::SeqCst) == generation {
+        AGENT_ACTION.store(Some(action));
+    } else {
+        info!(?action, "Discarding cancelled AI Agent action");
+    }
+}
+
+/// Starts a search for the current agent's next action using the `agent`
+/// [AgentName], searching for up to `think_time`. `seed` is used for the
+/// agent's random choices.
+pub fn initiate_selection(data: PlayPhaseData, agent: AgentName, think_time: Duration, seed: u64) {
+    info!(?agent, ?seed, "Starting AI Agent search");
+    let generation = GENERATION.load(Ordering::SeqCst);
+    let started = Instant::now();
     rayon::spawn(move || {
-        let agent = agents::get_agent(AgentName::Uct1);
-        let action = agent.pick_action(
+        let action = agents::get_agent(agent).pick_action(
             AgentConfig {
-                deadline: Instant::now() + Duration::from_secs(1),
+                deadline: started + think_time,
                 panic_on_search_timeout: false,
+                threads: 1,
+                seed: Some(seed),
             },
             &data,
         );
-        AGENT_ACTION.store(Some(action.into()));
+        store(generation, action.into());
     });
 }
 
-pub fn populate_agent_contracts(data: ContractPhaseData) {
-    info!("Starting AI contract selection");
+/// Selects contracts for each AI agent via [bidding::bid] and stores them as
+/// the next agent action. `seed` is used to sample deals for each agent.
+pub fn populate_agent_contracts(data: ContractPhaseData, seed: u64) {
+    info!(?seed, "Starting AI contract selection");
+    let generation = GENERATION.load(Ordering::SeqCst);
     rayon::spawn(move || {
-        let mut play_phase_data = data.to_play_phase();
-        run_matchup::run_match(
-            AgentName::Uct1MaxTricks,
-            AgentName::Uct1MaxTricks,
-            &mut play_phase_data,
-            100,
-            Verbosity::None,
-            false,
-        );
+        let play_phase_data = data.to_play_phase();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut contract = |player| bidding::bid(&play_phase_data, player, &mut rng).contract;
+        let west = contract(PlayerName::West);
+        let north = contract(PlayerName::North);
+        let east = contract(PlayerName::East);
 
-        let west = tricks::won(&play_phase_data, PlayerName::West);
-        let north = tricks::won(&play_phase_data, PlayerName::North);
-        let east = tricks::won(&play_phase_data, PlayerName::East);
-
-        AGENT_ACTION.store(Some(GameAction::ContractAction(
-            ContractPhaseAction::SetAgentContracts { west, north, east },
-        )))
+        store(
+            generation,
+            GameAction::ContractAction(ContractPhaseAction::SetAgentContracts {
+                west,
+                north,
+                east,
+            }),
+        );
     });
 }
@@ -16,10 +16,10 @@ use std::time::{Duration, Instant};
 
 use crate::core::game_state_node::{GameStateNode, GameStatus};
 use crate::core::selection_algorithm::SelectionAlgorithm;
-use crate::core::state_combiner::StateCombiner;
+use crate::core::state_combiner::Combination;
 use crate::core::state_evaluator::StateEvaluator;
 use crate::core::state_predictor::StatePredictor;
-use crate::core::{state_combiner, state_predictor};
+use crate::core::{search_rng, state_combiner, state_predictor};
 
 #[derive(Debug, Clone, Copy)]
 pub struct AgentConfig {
@@ -28,6 +28,12 @@ pub struct AgentConfig {
     /// If true, the agent should panic if it has not completed its search after
     /// the deadline is exceeded
     pub panic_on_search_timeout: bool,
+    /// Number of threads to use for selection algorithms which support
+    /// parallel search. A value of 1 runs the search on the calling thread.
+    pub threads: usize,
+    /// Seed for random choices made during the search, or None to seed from
+    /// system entropy. See [search_rng].
+    pub seed: Option<u64>,
 }
 
 impl AgentConfig {
@@ -35,6 +41,8 @@ impl AgentConfig {
         Self {
             deadline: Instant::now() + Duration::from_secs(seconds),
             panic_on_search_timeout: false,
+            threads: 1,
+            seed: None,
         }
     }
 }
@@ -85,9 +93,10 @@ where
     pub selector: TSelector,
     /// Produces a numeric score ranking the desirability of a game state.
     pub evaluator: TEvaluator,
-    /// Given a set of *possible* game states from the predictor, selects an
-    /// actual game state to run the selection algorithm form.
-    pub combiner: StateCombiner<TNode, TEvaluator>,
+    /// Determines how the set of *possible* game states from the predictor are
+    /// used to select an action, e.g. by selecting an actual game state to run
+    /// the selection algorithm from.
+    pub combiner: Combination<TNode, TEvaluator>,
 }
 
 impl<TSelector, TEvaluator, TNode> AgentData<TSelector, TEvaluator, TNode>
@@ -109,9 +118,39 @@ where
             predictor: state_predictor::omniscient,
             selector,
             evaluator,
-            combiner: state_combiner::worst_case,
+            combiner: Combination::Merged(state_combiner::worst_case),
         }
     }
+
+    /// Creates an Agent using the provided [StatePredictor] to enumerate
+    /// possible game states given the hidden information it cannot see. Uses
+    /// the [state_combiner::worst_case] combiner.
+    pub const fn with_predictor(
+        name: &'static str,
+        predictor: StatePredictor<TNode>,
+        selector: TSelector,
+        evaluator: TEvaluator,
+    ) -> Self {
+        Self {
+            name,
+            predictor,
+            selector,
+            evaluator,
+            combiner: Combination::Merged(state_combiner::worst_case),
+        }
+    }
+
+    /// Creates an Agent which runs its selection algorithm on each game state
+    /// returned by the provided [StatePredictor], picking an action via
+    /// [state_combiner::vote].
+    pub const fn determinized(
+        name: &'static str,
+        predictor: StatePredictor<TNode>,
+        selector: TSelector,
+        evaluator: TEvaluator,
+    ) -> Self {
+        Self { name, predictor, selector, evaluator, combiner: Combination::Determinized }
+    }
 }
 
 impl<TSelector, TEvaluator, TNode> Agent<TNode> for AgentData<TSelector, TEvaluator, TNode>
@@ -125,11 +164,23 @@ where
     }
 
     fn pick_action(&self, deadline: AgentConfig, node: &TNode) -> TNode::Action {
+        search_rng::seed(deadline.seed);
         let player = match node.status() {
             GameStatus::InProgress { current_turn } => current_turn,
             _ => panic!("Game is over"),
         };
-        let node = (self.combiner)(node, self.predictor, &self.evaluator);
-        self.selector.pick_action(deadline, &node, &self.evaluator, player)
+        match self.combiner {
+            Combination::Merged(combiner) => {
+                let node = combiner(node, self.predictor, &self.evaluator);
+                self.selector.pick_action(deadline, &node, &self.evaluator, player)
+            }
+            Combination::Determinized => state_combiner::vote(
+                deadline,
+                node,
+                self.predictor,
+                &self.selector,
+                &self.evaluator,
+            ),
+        }
     }
 }
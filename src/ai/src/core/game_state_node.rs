@@ -30,12 +30,14 @@ pub enum GameStatus<TPlayer: PartialEq + Eq + Copy + Hash + Debug> {
 /// useful for nim. We use a much simpler game with a known-optimal
 /// strategy (the game of Nim) to sanity-check that the AI implementations are
 /// doing broadly correct things.
-pub trait GameStateNode {
+///
+/// Game states must be thread-safe so that searches can be run in parallel.
+pub trait GameStateNode: Send + Sync {
     /// A game action to transition the game to a new state.
-    type Action: Eq + Copy + Hash + Debug;
+    type Action: Eq + Copy + Hash + Debug + Send + Sync;
 
     /// A player in the game.
-    type PlayerName: PartialEq + Eq + Copy + Hash + Debug;
+    type PlayerName: PartialEq + Eq + Copy + Hash + Debug + Send + Sync;
 
     /// Create a copy of this search node to be mutated by selection algorithms.
     /// A basic implementation of this would be to simply call `.clone()`, but
@@ -67,4 +69,18 @@ pub trait GameStateNode {
 
     /// Apply the result of a given action to this game state.
     fn execute_action(&mut self, player: Self::PlayerName, action: Self::Action);
+
+    /// Returns a hash identifying this game state, used by search algorithms
+    /// to detect transpositions (the same state reached via different action
+    /// sequences).
+    ///
+    /// Two states with the same hash are treated as interchangeable, so every
+    /// part of the state which can affect legal actions or evaluation must be
+    /// included. Typically implemented via [zobrist] hashing. The default
+    /// implementation returns None, which disables transposition detection.
+    ///
+    /// [zobrist]: crate::core::zobrist
+    fn zobrist_hash(&self) -> Option<u64> {
+        None
+    }
 }
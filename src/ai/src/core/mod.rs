@@ -16,8 +16,10 @@ pub mod agent;
 pub mod compound_evaluator;
 pub mod first_available_action;
 pub mod game_state_node;
+pub mod search_rng;
 pub mod selection_algorithm;
 pub mod state_combiner;
 pub mod state_evaluator;
 pub mod state_predictor;
 pub mod win_loss_evaluator;
+pub mod zobrist;
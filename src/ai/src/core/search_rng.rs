@@ -0,0 +1,67 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Random number generation for agent searches.
+//!
+//! Evaluators and predictors are invoked deep inside selection algorithms and
+//! do not receive the [AgentConfig], so the generator they draw from lives in
+//! a thread-local which agents reseed from [AgentConfig::seed] before each
+//! search. Parallel searches seed each worker thread from the same value.
+//!
+//! A fixed seed makes every random choice in a search reproducible. Searches
+//! which stop at a deadline rather than after a fixed number of iterations can
+//! still differ between runs, since the amount of work they complete varies.
+//!
+//! [AgentConfig]: crate::core::agent::AgentConfig
+//! [AgentConfig::seed]: crate::core::agent::AgentConfig::seed
+
+use std::cell::RefCell;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+thread_local! {
+    static RNG: RefCell<StdRng> = RefCell::new(StdRng::from_entropy());
+}
+
+/// Reseeds the generator for the current thread, or seeds it from system
+/// entropy if `seed` is None.
+pub fn seed(seed: Option<u64>) {
+    let rng = seed.map_or_else(StdRng::from_entropy, StdRng::seed_from_u64);
+    RNG.with(|cell| *cell.borrow_mut() = rng);
+}
+
+/// Seeds the generator for the `index`-th worker thread of a parallel search.
+pub fn seed_worker(seed: Option<u64>, index: usize) {
+    self::seed(seed.map(|s| s.wrapping_add(index as u64)));
+}
+
+/// Derives a new seed from `seed` which is unrelated to it, e.g. to seed a
+/// second stream of random values which should not repeat the first.
+///
+/// Uses the SplitMix64 output function, so nearby inputs such as consecutive
+/// match seeds produce very different outputs.
+pub const fn derive_seed(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Invokes `function` with the generator for the current thread.
+///
+/// Panics if called re-entrantly from within `function`.
+pub fn with<T>(function: impl FnOnce(&mut StdRng) -> T) -> T {
+    RNG.with(|cell| function(&mut cell.borrow_mut()))
+}
@@ -12,7 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::time::Instant;
+
+use tracing::debug;
+
+use crate::core::agent::AgentConfig;
 use crate::core::game_state_node::{GameStateNode, GameStatus};
+use crate::core::selection_algorithm::SelectionAlgorithm;
 use crate::core::state_evaluator::StateEvaluator;
 use crate::core::state_predictor::StatePredictor;
 
@@ -21,6 +29,17 @@ use crate::core::state_predictor::StatePredictor;
 /// use as the basis for a selection algorithm.
 pub type StateCombiner<TNode, TEvaluator> = fn(&TNode, StatePredictor<TNode>, &TEvaluator) -> TNode;
 
+/// Determines how an agent makes use of the game states returned by its
+/// [StatePredictor].
+pub enum Combination<TNode, TEvaluator> {
+    /// Merge all predicted states into a single game state using a
+    /// [StateCombiner], then run the selection algorithm once on that state.
+    Merged(StateCombiner<TNode, TEvaluator>),
+    /// Run the selection algorithm separately on each predicted state and
+    /// aggregate the results via [vote].
+    Determinized,
+}
+
 /// A pessimistic [StateCombiner]. Evaluates each predicted game state and picks
 /// the one which appears to be the worst-case scenario (i.e. has the lowest
 /// [StateEvaluator] score) for the current player.
@@ -49,3 +68,54 @@ where
 
     worst_state.expect("No state found")
 }
+
+/// Perfect-information Monte Carlo action selection.
+///
+/// Runs the `selector` on each game state returned by the `predictor`,
+/// splitting the remaining search time evenly between them, and returns the
+/// action which was selected in the largest number of states. States are
+/// skipped once the deadline has passed. Ties are broken by the total
+/// `evaluator` score of the states resulting from applying each action.
+pub fn vote<TNode, TSelector, TEvaluator>(
+    config: AgentConfig,
+    node: &TNode,
+    predictor: StatePredictor<TNode>,
+    selector: &TSelector,
+    evaluator: &TEvaluator,
+) -> TNode::Action
+where
+    TNode: GameStateNode,
+    TSelector: SelectionAlgorithm,
+    TEvaluator: StateEvaluator<TNode>,
+{
+    let player = node.current_turn();
+    let states = predictor(node).collect::<Vec<_>>();
+    let mut votes = HashMap::new();
+    for (i, state) in states.iter().enumerate() {
+        if !votes.is_empty() && config.deadline < Instant::now() {
+            break;
+        }
+        let remaining = config.deadline.saturating_duration_since(Instant::now());
+        let deadline = Instant::now() + remaining / (states.len() - i) as u32;
+        let action =
+            selector.pick_action(AgentConfig { deadline, ..config }, state, evaluator, player);
+        let mut child = state.make_copy();
+        child.execute_action(player, action);
+        let order = votes.len();
+        let (count, score, _) = votes.entry(action).or_insert((0, 0, order));
+        *count += 1;
+        *score += evaluator.evaluate(&child, player);
+    }
+
+    for (action, (count, score, _)) in &votes {
+        debug!("Votes {:?} with score {:?} for action {:?}", count, score, action);
+    }
+
+    // Ties go to the action which was voted for first, so the result doesn't
+    // depend on hash map iteration order.
+    votes
+        .into_iter()
+        .max_by_key(|(_, (count, score, order))| (*count, *score, Reverse(*order)))
+        .map(|(action, _)| action)
+        .expect("No states predicted")
+}
@@ -15,7 +15,7 @@
 use crate::core::game_state_node::GameStateNode;
 
 /// A trait implementation for producing a 'score' for a given game state.
-pub trait StateEvaluator<TNode: GameStateNode>: Send {
+pub trait StateEvaluator<TNode: GameStateNode>: Send + Sync {
     /// Evaluate the heuristic value of the given game state for the provided
     /// `player`, returning a higher number for "better" game states.
     ///
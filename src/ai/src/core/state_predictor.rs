@@ -33,9 +33,9 @@ pub type StatePredictor<TNode> = fn(&TNode) -> Box<dyn Iterator<Item = TNode>>;
 ///
 /// This creates an agent with perfect information about hidden game state, i.e.
 /// one who cheats.
-pub fn omniscient<N: 'static>(node: &N) -> Box<dyn Iterator<Item = N>>
+pub fn omniscient<N>(node: &N) -> Box<dyn Iterator<Item = N>>
 where
-    N: GameStateNode,
+    N: GameStateNode + 'static,
 {
     Box::new(iter::once(node.make_copy()))
 }
@@ -0,0 +1,45 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for Zobrist hashing of game states.
+//!
+//! A Zobrist hash assigns a random 64-bit key to each individual feature of a
+//! game state (e.g. "the ace of spades is in north's hand") and combines the
+//! keys of every feature present in the state via XOR. Keys are derived from
+//! the feature index with a fixed mixing function, so hashes are stable across
+//! runs and no key table needs to be stored.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// Returns the Zobrist key for the feature with the given index.
+///
+/// This is the SplitMix64 output function, which maps distinct inputs to
+/// well-distributed outputs.
+pub const fn key(feature: u64) -> u64 {
+    let mut z = feature.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Returns the Zobrist key for an arbitrary hashable feature value.
+///
+/// Useful for features which do not have a natural dense index, such as
+/// program state.
+pub fn key_for(feature: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    feature.hash(&mut hasher);
+    key(hasher.finish())
+}
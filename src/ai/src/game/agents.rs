@@ -16,22 +16,37 @@ use std::marker::PhantomData;
 
 use clap::ValueEnum;
 use data::play_phase_data::PlayPhaseData;
+use serde::{Deserialize, Serialize};
 
 use crate::core::agent::{Agent, AgentData};
 use crate::core::first_available_action::FirstAvailableActionAlgorithm;
 use crate::core::win_loss_evaluator::WinLossEvaluator;
-use crate::game::evaluators::{MaxTricksEvaluator, TrickEvaluator};
-use crate::monte_carlo::monte_carlo_search::{MonteCarloAlgorithm, RandomPlayoutEvaluator};
+use crate::game::double_dummy::DoubleDummyAgent;
+use crate::game::evaluators::{ContractEvaluator, MaxTricksEvaluator, TrickEvaluator};
+use crate::game::predictors;
+use crate::game::rollout_policies::TrickTakingRollout;
+use crate::monte_carlo::information_set_search::InformationSetMonteCarloAgent;
+use crate::monte_carlo::monte_carlo_search::{MonteCarloAlgorithm, Parallelism, PlayoutEvaluator};
+use crate::monte_carlo::rollout_policy::{EpsilonGreedy, RandomRollout};
 use crate::monte_carlo::uct1::Uct1;
 use crate::tree_search::alpha_beta::AlphaBetaAlgorithm;
 
-#[derive(ValueEnum, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(ValueEnum, Copy, Clone, Eq, PartialEq, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum AgentName {
     AlphaBetaDepth10,
     AlphaBetaDepth13,
+    AlphaBetaContract,
     Uct1,
+    Uct1TreeParallel,
+    Uct1Heuristic,
+    Uct1EpsilonGreedy,
+    Uct1InformationSet,
+    Uct1Determinized,
     Uct1MaxTricks,
     Uct1Iterations250,
+    Ismcts,
+    DoubleDummy,
     FirstAvailableAction,
 }
 
@@ -47,21 +62,129 @@ pub fn get_agent(name: AgentName) -> Box<dyn Agent<PlayPhaseData>> {
             AlphaBetaAlgorithm { search_depth: 13 },
             TrickEvaluator,
         )),
+        AgentName::AlphaBetaContract => Box::new(AgentData::omniscient(
+            "ALPHA_BETA_CONTRACT",
+            AlphaBetaAlgorithm { search_depth: 10 },
+            ContractEvaluator,
+        )),
         AgentName::Uct1 => Box::new(AgentData::omniscient(
             "UCT1",
-            MonteCarloAlgorithm { child_score_algorithm: Uct1 {}, max_iterations: None },
-            RandomPlayoutEvaluator { evaluator: TrickEvaluator, phantom_data: PhantomData },
+            MonteCarloAlgorithm {
+                child_score_algorithm: Uct1 {},
+                max_iterations: None,
+                parallelism: Parallelism::Root,
+            },
+            PlayoutEvaluator {
+                evaluator: TrickEvaluator,
+                policy: RandomRollout,
+                phantom_data: PhantomData,
+            },
+        )),
+        AgentName::Uct1TreeParallel => Box::new(AgentData::omniscient(
+            "UCT1_TREE_PARALLEL",
+            MonteCarloAlgorithm {
+                child_score_algorithm: Uct1 {},
+                max_iterations: None,
+                parallelism: Parallelism::Tree,
+            },
+            PlayoutEvaluator {
+                evaluator: TrickEvaluator,
+                policy: RandomRollout,
+                phantom_data: PhantomData,
+            },
+        )),
+        AgentName::Uct1Heuristic => Box::new(AgentData::omniscient(
+            "UCT1_HEURISTIC",
+            MonteCarloAlgorithm {
+                child_score_algorithm: Uct1 {},
+                max_iterations: None,
+                parallelism: Parallelism::Root,
+            },
+            PlayoutEvaluator {
+                evaluator: TrickEvaluator,
+                policy: TrickTakingRollout,
+                phantom_data: PhantomData,
+            },
+        )),
+        AgentName::Uct1EpsilonGreedy => Box::new(AgentData::omniscient(
+            "UCT1_EPSILON_GREEDY",
+            MonteCarloAlgorithm {
+                child_score_algorithm: Uct1 {},
+                max_iterations: None,
+                parallelism: Parallelism::Root,
+            },
+            PlayoutEvaluator {
+                evaluator: TrickEvaluator,
+                policy: EpsilonGreedy { epsilon: 0.1, policy: TrickTakingRollout },
+                phantom_data: PhantomData,
+            },
+        )),
+        AgentName::Uct1InformationSet => Box::new(AgentData::with_predictor(
+            "UCT1_INFORMATION_SET",
+            predictors::information_set,
+            MonteCarloAlgorithm {
+                child_score_algorithm: Uct1 {},
+                max_iterations: None,
+                parallelism: Parallelism::Root,
+            },
+            PlayoutEvaluator {
+                evaluator: TrickEvaluator,
+                policy: RandomRollout,
+                phantom_data: PhantomData,
+            },
+        )),
+        AgentName::Uct1Determinized => Box::new(AgentData::determinized(
+            "UCT1_DETERMINIZED",
+            predictors::information_set,
+            MonteCarloAlgorithm {
+                child_score_algorithm: Uct1 {},
+                max_iterations: None,
+                parallelism: Parallelism::Root,
+            },
+            PlayoutEvaluator {
+                evaluator: TrickEvaluator,
+                policy: RandomRollout,
+                phantom_data: PhantomData,
+            },
         )),
         AgentName::Uct1MaxTricks => Box::new(AgentData::omniscient(
             "UCT1_MAX_TRICKS",
-            MonteCarloAlgorithm { child_score_algorithm: Uct1 {}, max_iterations: None },
-            RandomPlayoutEvaluator { evaluator: MaxTricksEvaluator, phantom_data: PhantomData },
+            MonteCarloAlgorithm {
+                child_score_algorithm: Uct1 {},
+                max_iterations: None,
+                parallelism: Parallelism::Root,
+            },
+            PlayoutEvaluator {
+                evaluator: MaxTricksEvaluator,
+                policy: RandomRollout,
+                phantom_data: PhantomData,
+            },
         )),
         AgentName::Uct1Iterations250 => Box::new(AgentData::omniscient(
             "UCT1_250",
-            MonteCarloAlgorithm { child_score_algorithm: Uct1 {}, max_iterations: Some(250) },
-            RandomPlayoutEvaluator { evaluator: TrickEvaluator, phantom_data: PhantomData },
+            MonteCarloAlgorithm {
+                child_score_algorithm: Uct1 {},
+                max_iterations: Some(250),
+                parallelism: Parallelism::Root,
+            },
+            PlayoutEvaluator {
+                evaluator: TrickEvaluator,
+                policy: RandomRollout,
+                phantom_data: PhantomData,
+            },
         )),
+        AgentName::Ismcts => Box::new(InformationSetMonteCarloAgent {
+            name: "ISMCTS",
+            predictor: predictors::determinizations,
+            child_score_algorithm: Uct1 {},
+            evaluator: PlayoutEvaluator {
+                evaluator: TrickEvaluator,
+                policy: RandomRollout,
+                phantom_data: PhantomData,
+            },
+            max_iterations: None,
+        }),
+        AgentName::DoubleDummy => Box::new(DoubleDummyAgent),
         AgentName::FirstAvailableAction => Box::new(AgentData::omniscient(
             "FIRST_AVAILABLE_ACTION",
             FirstAvailableActionAlgorithm,
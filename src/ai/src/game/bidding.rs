@@ -0,0 +1,120 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contract selection for AI agents.
+//!
+//! Agents bid by estimating how many tricks they are likely to win with their
+//! own hand, without looking at anyone else's cards, and then picking the
+//! contract with the highest expected score:
+//!
+//! ```text
+//! for each of DEAL_SAMPLES deals:
+//!   randomly redistribute the cards not in the agent's hand
+//!   play the deal out with TrickTakingRollout for every player
+//!   record the number of tricks the agent won
+//! P(k) ← fraction of deals in which the agent won exactly k tricks
+//! for each contract c:
+//!   EV(c) ← contract_value(c) × Σ P(k) for k ≥ c
+//! bid argmax EV(c), preferring lower contracts on ties
+//! ```
+
+use std::fmt::{Display, Formatter};
+
+use data::contract_phase_data::ContractNumber;
+use data::play_phase_data::PlayPhaseData;
+use data::primitive::primitives::PlayerName;
+use rand::Rng;
+use rules::rounds::{scoring, tricks};
+use tracing::debug;
+
+use crate::core::game_state_node::{GameStateNode, GameStatus};
+use crate::game::predictors::InformationSet;
+use crate::game::rollout_policies::TrickTakingRollout;
+use crate::monte_carlo::rollout_policy::RolloutPolicy;
+
+/// Number of random deals to play out when estimating a trick distribution.
+pub const DEAL_SAMPLES: usize = 200;
+
+/// Maximum number of tricks which can be won in a round.
+const MAX_TRICKS: usize = 13;
+
+/// A contract selected by [bid], along with the estimates it was based on.
+#[derive(Debug, Clone)]
+pub struct Bid {
+    pub player: PlayerName,
+    pub contract: ContractNumber,
+    /// Probability of winning exactly `k` tricks, indexed by `k`.
+    pub distribution: [f64; MAX_TRICKS + 1],
+    /// Expected score for each possible contract, indexed by contract number.
+    pub expected_values: [f64; MAX_TRICKS + 1],
+}
+
+impl Display for Bid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} bids {} (expected score {:.1}, P(make) {:.2})",
+            self.player,
+            self.contract,
+            self.expected_values[self.contract],
+            self.distribution[self.contract..].iter().sum::<f64>()
+        )
+    }
+}
+
+/// Selects a contract for `player` using only the cards in their own hand.
+///
+/// The result is deterministic for a given deal and `rng` state.
+pub fn bid(data: &PlayPhaseData, player: PlayerName, rng: &mut impl Rng) -> Bid {
+    let distribution = trick_distribution(data, player, DEAL_SAMPLES, rng);
+    let mut expected_values = [0.0; MAX_TRICKS + 1];
+    for (contract, value) in expected_values.iter_mut().enumerate() {
+        let probability = distribution[contract..].iter().sum::<f64>();
+        *value = probability * f64::from(scoring::contract_value(contract).0);
+    }
+
+    let mut contract = 0;
+    for (candidate, &value) in expected_values.iter().enumerate() {
+        if value > expected_values[contract] {
+            contract = candidate;
+        }
+    }
+
+    let result = Bid { player, contract, distribution, expected_values };
+    debug!(?result.distribution, ?result.expected_values, "{result}");
+    result
+}
+
+/// Estimates the probability of `player` winning each possible number of
+/// tricks, based on playing out `samples` random deals of the cards not in
+/// their hand.
+pub fn trick_distribution(
+    data: &PlayPhaseData,
+    player: PlayerName,
+    samples: usize,
+    rng: &mut impl Rng,
+) -> [f64; MAX_TRICKS + 1] {
+    let mut counts = [0; MAX_TRICKS + 1];
+    let information_set = InformationSet::new(data, player);
+    for _ in 0..samples {
+        let mut game = information_set.sample(rng);
+        while let GameStatus::InProgress { current_turn } = game.status() {
+            let action = TrickTakingRollout.select_action(&game, current_turn, rng);
+            game.execute_action(current_turn, action);
+        }
+        counts[tricks::won(&game, player).min(MAX_TRICKS)] += 1;
+    }
+
+    counts.map(|count| f64::from(count) / samples.max(1) as f64)
+}
@@ -18,6 +18,15 @@ use rules::play_phase::{play_phase_actions, play_phase_queries};
 use rules::rounds::scoring;
 
 use crate::core::game_state_node::{GameStateNode, GameStatus};
+use crate::core::zobrist;
+
+/// Zobrist feature index offsets for [PlayPhaseData]. Each feature family
+/// occupies a disjoint range of indices.
+const HAND_FEATURES: u64 = 0;
+const TRICK_FEATURES: u64 = 4 * 52;
+const TURN_FEATURES: u64 = 8 * 52;
+const TRUMP_FEATURES: u64 = TURN_FEATURES + 4;
+const TRICKS_WON_FEATURES: u64 = TRUMP_FEATURES + 4;
 
 impl GameStateNode for PlayPhaseData {
     type Action = PlayPhaseAction;
@@ -49,4 +58,41 @@ impl GameStateNode for PlayPhaseData {
     fn execute_action(&mut self, player_name: Self::PlayerName, action: Self::Action) {
         play_phase_actions::handle_action(self, player_name, action);
     }
+
+    /// Hashes the cards in each hand, the cards in the current trick, the
+    /// current turn, trump suit, number of tricks won by each player, and the
+    /// state of each program. Contracts and program ownership are fixed for
+    /// the duration of a round and so are not included.
+    fn zobrist_hash(&self) -> Option<u64> {
+        let mut won = [0; 4];
+        for completed in &self.completed_tricks {
+            won[completed.winner as usize] += 1;
+        }
+
+        let mut hash = 0;
+        for player in enum_iterator::all::<PlayerName>() {
+            let offset = player as u64 * 52;
+            for card in self.hands.hand(player) {
+                hash ^= zobrist::key(HAND_FEATURES + offset + card as u64);
+            }
+            hash ^= zobrist::key(TRICKS_WON_FEATURES + player as u64 * 14 + won[player as usize]);
+        }
+        for played in &self.current_trick.cards {
+            hash ^=
+                zobrist::key(TRICK_FEATURES + played.played_by as u64 * 52 + played.card as u64);
+        }
+        if let Some(turn) = self.turn {
+            hash ^= zobrist::key(TURN_FEATURES + turn as u64);
+        }
+        if let Some(trump) = self.trump {
+            hash ^= zobrist::key(TRUMP_FEATURES + trump as u64);
+        }
+        for (id, state) in &self.programs.program_state {
+            hash ^= zobrist::key_for(&("state", id, state));
+        }
+        for id in &self.programs.activated {
+            hash ^= zobrist::key_for(&("activated", id));
+        }
+        Some(hash)
+    }
 }
@@ -0,0 +1,705 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A double-dummy solver for the play phase.
+//!
+//! Computes the number of tricks a player can win with perfect play when every
+//! hand is visible, assuming the other three players cooperate to minimize
+//! that number. Program activations are not considered: the solver uses the
+//! current trump suit and standard follow-suit and trick-winner rules for all
+//! remaining tricks.
+//!
+//! Endgames solve almost instantly, but the three-against-one search over a
+//! full 13-trick deal can take anywhere from well under a second to around a
+//! minute depending on the deal. [DoubleDummyAgent] stops at its deadline and
+//! plays the best card found so far.
+
+use std::collections::HashMap;
+use std::time::Instant;
+use std::{cmp, mem};
+
+use data::play_phase_data::{PlayPhaseAction, PlayPhaseData, PlayedCard};
+use data::primitive::primitives::{Card, PlayerName, Suit};
+use enumset::EnumSet;
+use rules::rounds::tricks;
+
+use crate::core::agent::{Agent, AgentConfig};
+use crate::core::game_state_node::GameStateNode;
+
+/// Returns the total number of tricks `player` will win in this round with
+/// double-dummy play, including tricks which have already been won.
+pub fn max_tricks(data: &PlayPhaseData, player: PlayerName) -> usize {
+    tricks::won(data, player) + DoubleDummySolver::new(data, player).solve()
+}
+
+/// Agent which plays the card chosen by the [DoubleDummySolver]. This agent
+/// can see every hand and never activates programs.
+pub struct DoubleDummyAgent;
+
+impl Agent<PlayPhaseData> for DoubleDummyAgent {
+    fn name(&self) -> &'static str {
+        "DOUBLE_DUMMY"
+    }
+
+    fn pick_action(&self, config: AgentConfig, node: &PlayPhaseData) -> PlayPhaseAction {
+        let player = node.current_turn();
+        let mut solver = DoubleDummySolver::new(node, player);
+        solver.set_deadline(config.deadline);
+        let (card, _) = solver.best_card();
+        if node.legal_actions(player).any(|a| a == PlayPhaseAction::PlayCard(card)) {
+            PlayPhaseAction::PlayCard(card)
+        } else {
+            // A program may have changed which cards are legal to play
+            node.legal_actions(player)
+                .find(|a| matches!(a, PlayPhaseAction::PlayCard(_)))
+                .expect("No legal cards to play")
+        }
+    }
+}
+
+/// Maximum number of entries in the transposition table before it is cleared.
+const MAX_TABLE_SIZE: usize = 1 << 22;
+
+/// Positions with this many tricks or fewer remaining are cheap to search, so
+/// they are not stored in the transposition table.
+const MIN_STORED_TRICKS: i32 = 2;
+
+/// Number of positions searched between checks of the deadline.
+const DEADLINE_CHECK_INTERVAL: u64 = 4096;
+
+/// Key for positions in the transposition table. Positions are only stored at
+/// the start of a trick, so they are fully identified by the remaining cards in
+/// each hand and the player who has the lead.
+///
+/// Packs the owner of each remaining card (2 bits per card, ordered by suit
+/// and then rank), the number of remaining cards in each suit, and the leader.
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+struct TableKey(u128);
+
+/// Known bounds on the number of future tricks the solving player can win from
+/// a position.
+#[derive(Clone, Copy)]
+struct Bounds {
+    lower: i32,
+    upper: i32,
+}
+
+/// Alpha-beta search over the remaining card play of a round, specialized for
+/// trick-taking.
+///
+/// The exact value of a position is found via a sequence of null-window
+/// searches. In addition to a transposition table keyed by relative card
+/// ranks, the search uses several pruning techniques:
+///
+/// - **Equivalent cards:** Cards in the same suit with no card held by another
+///   player (or played to the current trick) between them in rank are
+///   interchangeable, so only one of them is searched.
+/// - **Quick tricks:** When a player has the lead, they can immediately cash
+///   each card which is the highest remaining card in its suit, provided no
+///   other player can trump it. This provides a bound on the search value
+///   without needing to search the position.
+/// - **Master trumps:** Trumps which are higher than every trump held by
+///   another player will always win a trick, bounding the search value in the
+///   same way.
+pub struct DoubleDummySolver {
+    player: PlayerName,
+    trump: Option<Suit>,
+    hands: [EnumSet<Card>; 4],
+    trick: Vec<PlayedCard>,
+    leader: PlayerName,
+    /// Tricks completed during search, along with the player who led them.
+    completed: Vec<(Vec<PlayedCard>, PlayerName)>,
+    table: HashMap<TableKey, Bounds>,
+    /// History heuristic scores for each player and card, incremented whenever
+    /// playing the card produces a cutoff.
+    history: [[u32; 52]; 4],
+    /// Time after which searching stops, if any.
+    deadline: Option<Instant>,
+    /// True if the deadline passed. Values computed since are meaningless.
+    aborted: bool,
+    /// Number of positions searched, used to limit deadline checks.
+    nodes: u64,
+}
+
+impl DoubleDummySolver {
+    /// Creates a new solver for the current position in `data`, maximizing the
+    /// number of tricks won by `player`.
+    pub fn new(data: &PlayPhaseData, player: PlayerName) -> Self {
+        let leader = if let Some(first) = data.current_trick.cards.first() {
+            first.played_by
+        } else {
+            data.turn.expect("Game is over")
+        };
+        Self {
+            player,
+            trump: data.trump,
+            hands: enum_iterator::all::<PlayerName>()
+                .map(|p| data.hands.hand(p))
+                .collect::<Vec<_>>()
+                .try_into()
+                .expect("Expected four hands"),
+            trick: data.current_trick.cards.clone(),
+            leader,
+            completed: vec![],
+            table: HashMap::new(),
+            history: [[0; 52]; 4],
+            deadline: None,
+            aborted: false,
+            nodes: 0,
+        }
+    }
+
+    /// Stops searching once `deadline` has passed. Only [Self::best_card]
+    /// produces a useful result after this happens.
+    pub fn set_deadline(&mut self, deadline: Instant) {
+        self.deadline = Some(deadline);
+    }
+
+    /// Returns the number of tricks the solving player will win from the
+    /// current position, not including tricks which have already been won.
+    pub fn solve(&mut self) -> usize {
+        self.value() as usize
+    }
+
+    /// Returns the best card for the player whose turn it currently is to play
+    /// along with the number of future tricks the solving player will win if
+    /// it is played.
+    ///
+    /// If the deadline passes, returns the best of the cards which were fully
+    /// searched, or the first card in move order if none were.
+    pub fn best_card(&mut self) -> (Card, usize) {
+        let turn = self.turn();
+        let maximizing = turn == self.player;
+        let moves = self.moves(turn);
+        let mut best: Option<(Card, i32)> = None;
+        for &card in &moves {
+            let won = self.play(turn, card);
+            let value = won + self.value();
+            self.undo(turn);
+            if self.aborted {
+                break;
+            }
+            let better = match best {
+                None => true,
+                Some((_, score)) if maximizing => value > score,
+                Some((_, score)) => value < score,
+            };
+            if better {
+                best = Some((card, value));
+            }
+        }
+        let (card, value) =
+            best.or_else(|| moves.first().map(|&card| (card, 0))).expect("No cards to play");
+        (card, value as usize)
+    }
+
+    /// Computes the exact value of the current position via a binary search
+    /// over null-window searches, each of which answers the question "can the
+    /// solving player win at least `target` tricks?". Narrow windows produce
+    /// far more cutoffs than a single full-window search.
+    fn value(&mut self) -> i32 {
+        let (mut lower, mut upper) = (0, self.remaining_tricks());
+        while lower < upper {
+            let target = (lower + upper + 1) / 2;
+            let value = self.search(target - 1, target);
+            if value >= target {
+                lower = value;
+            } else {
+                upper = value;
+            }
+        }
+        lower
+    }
+
+    /// Returns the number of future tricks the solving player will win from
+    /// the current position, assuming the result lies within (alpha, beta).
+    /// Results outside of this window are bounds on the true value.
+    fn search(&mut self, mut alpha: i32, mut beta: i32) -> i32 {
+        let turn = self.turn();
+        let remaining = self.remaining_tricks();
+        if remaining == 0 || self.deadline_exceeded() {
+            return 0;
+        }
+
+        let key = self.trick.is_empty().then(|| self.table_key());
+        if let Some(key) = key {
+            let stored = self.table.get(&key).copied();
+            let bounds = self.trick_start_bounds(turn, remaining);
+            let bounds = stored.map_or(bounds, |stored| Bounds {
+                lower: cmp::max(bounds.lower, stored.lower),
+                upper: cmp::min(bounds.upper, stored.upper),
+            });
+            if bounds.lower >= beta || bounds.lower == bounds.upper {
+                return bounds.lower;
+            }
+            if bounds.upper <= alpha {
+                return bounds.upper;
+            }
+            alpha = cmp::max(alpha, bounds.lower);
+            beta = cmp::min(beta, bounds.upper);
+        }
+
+        let (original_alpha, original_beta) = (alpha, beta);
+        let maximizing = turn == self.player;
+        let mut best = if maximizing { -1 } else { remaining + 1 };
+        for card in self.moves(turn) {
+            let won = self.play(turn, card);
+            let value = won + self.search(alpha - won, beta - won);
+            self.undo(turn);
+            if maximizing {
+                best = cmp::max(best, value);
+                alpha = cmp::max(alpha, value);
+            } else {
+                best = cmp::min(best, value);
+                beta = cmp::min(beta, value);
+            }
+            if alpha >= beta {
+                self.history[turn as usize][card as usize] += (remaining * remaining) as u32;
+                break;
+            }
+        }
+
+        if let Some(key) = key.filter(|_| remaining > MIN_STORED_TRICKS && !self.aborted) {
+            if self.table.len() >= MAX_TABLE_SIZE {
+                self.table.clear();
+            }
+            let entry = self.table.entry(key).or_insert(Bounds { lower: 0, upper: remaining });
+            if best <= original_alpha {
+                entry.upper = cmp::min(entry.upper, best);
+            } else if best >= original_beta {
+                entry.lower = cmp::max(entry.lower, best);
+            } else {
+                *entry = Bounds { lower: best, upper: best };
+            }
+        }
+
+        best
+    }
+
+    /// Returns true if searching should stop because the deadline has passed.
+    fn deadline_exceeded(&mut self) -> bool {
+        self.nodes += 1;
+        if !self.aborted && self.nodes.is_multiple_of(DEADLINE_CHECK_INTERVAL) {
+            self.aborted = self.deadline.is_some_and(|deadline| deadline < Instant::now());
+        }
+        self.aborted
+    }
+
+    /// Plays `card` from the hand of `player`, returning 1 if this completed a
+    /// trick won by the solving player and 0 otherwise.
+    fn play(&mut self, player: PlayerName, card: Card) -> i32 {
+        self.hands[player as usize].remove(card);
+        self.trick.push(PlayedCard { played_by: player, card });
+        if self.trick.len() < 4 {
+            return 0;
+        }
+
+        let winner = self.trick_winner();
+        self.completed.push((mem::take(&mut self.trick), self.leader));
+        self.leader = winner;
+        i32::from(winner == self.player)
+    }
+
+    /// Reverses the most recent call to [Self::play] by `player`.
+    fn undo(&mut self, player: PlayerName) {
+        if self.trick.is_empty() {
+            (self.trick, self.leader) = self.completed.pop().expect("No trick to undo");
+        }
+        let played = self.trick.pop().expect("No card to undo");
+        self.hands[player as usize].insert(played.card);
+    }
+
+    fn turn(&self) -> PlayerName {
+        self.trick.last().map_or(self.leader, |last| last.played_by.next())
+    }
+
+    /// Number of tricks remaining, including the current trick.
+    fn remaining_tricks(&self) -> i32 {
+        self.hands[self.turn() as usize].len() as i32
+    }
+
+    /// Builds a table key from the *relative* rank of each remaining card
+    /// within its suit, so that positions which differ only in which
+    /// already-played cards are missing share an entry. Must only be called at
+    /// the start of a trick.
+    fn table_key(&self) -> TableKey {
+        let [_, west, north, east] = self.hands.map(|h| h.as_u64());
+        let (low, high) = (west | east, north | east);
+        let occupied = self.occupied();
+        let mut owners = 0u128;
+        let mut cards = occupied;
+        while cards != 0 {
+            let i = cards.trailing_zeros();
+            cards &= cards - 1;
+            owners = (owners << 2) | u128::from((low >> i) & 1) | u128::from((high >> i) & 1) << 1;
+        }
+        let lengths = enum_iterator::all::<Suit>().fold(0u128, |acc, suit| {
+            acc | u128::from((occupied & suit_mask(suit)).count_ones()) << (suit as usize * 4)
+        });
+        TableKey(owners | (lengths << 104) | ((self.leader as u128) << 120))
+    }
+
+    /// Returns the winner of the current trick.
+    fn trick_winner(&self) -> PlayerName {
+        let suit = self.trick[0].card.suit();
+        self.trick
+            .iter()
+            .max_by_key(|played| self.strength(played.card, suit))
+            .expect("Trick was empty")
+            .played_by
+    }
+
+    /// Sort key for the strength of `card` in a trick where `led` was led.
+    fn strength(&self, card: Card, led: Suit) -> (bool, bool, Card) {
+        (Some(card.suit()) == self.trump, card.suit() == led, card)
+    }
+
+    /// Returns the cards `player` can legally play, removing cards which are
+    /// equivalent to a higher card in the same suit.
+    ///
+    /// Cards are ordered by their history heuristic score, highest first. When
+    /// following, cards which leave the trick held by `player`'s side are
+    /// always tried before those which do not. Ties are broken from highest to
+    /// lowest rank when leading and from lowest to highest otherwise.
+    fn moves(&self, player: PlayerName) -> Vec<Card> {
+        let hand = self.hands[player as usize];
+        let mut legal = hand.as_u64();
+        if let Some(first) = self.trick.first() {
+            let following = legal & suit_mask(first.card.suit());
+            if following != 0 {
+                legal = following;
+            }
+        }
+
+        let others = self.occupied() & !hand.as_u64();
+        let history = &self.history[player as usize];
+        let mut result = vec![];
+        let mut previous: Option<Card> = None;
+        for card in EnumSet::<Card>::from_u64(legal).iter().rev() {
+            let equivalent = previous.is_some_and(|higher| {
+                let between = (1u64 << higher as u32) - (2u64 << card as u32);
+                higher.suit() == card.suit() && others & between == 0
+            });
+            if !equivalent {
+                result.push(card);
+            }
+            previous = Some(card);
+        }
+
+        if let Some(first) = self.trick.first() {
+            let led = first.card.suit();
+            let winner = self
+                .trick
+                .iter()
+                .max_by_key(|played| self.strength(played.card, led))
+                .expect("Trick was empty");
+            let ours = |p: PlayerName| (p == self.player) == (player == self.player);
+            result.reverse();
+            result.sort_by_key(|&card| {
+                let wins = self.strength(card, led) > self.strength(winner.card, led);
+                (!(wins || ours(winner.played_by)), cmp::Reverse(history[card as usize]))
+            });
+        } else {
+            result.sort_by_key(|&card| cmp::Reverse(history[card as usize]));
+        }
+        result
+    }
+
+    /// Cards which are either still in a hand or have been played to the
+    /// current trick.
+    fn occupied(&self) -> u64 {
+        let hands = self.hands.iter().fold(0, |acc, h| acc | h.as_u64());
+        self.trick.iter().fold(hands, |acc, played| acc | (1 << played.card as u32))
+    }
+
+    /// Returns bounds on the value of a position at the start of a trick where
+    /// `leader` has the lead, without searching it.
+    ///
+    /// In addition to quick tricks for the leader, each of the solving
+    /// player's trumps which are the highest remaining trumps is certain to win
+    /// a trick, as are those of each opponent individually.
+    fn trick_start_bounds(&self, leader: PlayerName, remaining: i32) -> Bounds {
+        let quick = self.quick_tricks(leader);
+        let (mut lower, mut upper) =
+            if leader == self.player { (quick, remaining) } else { (0, remaining - quick) };
+        if let Some(trump) = self.trump {
+            lower = cmp::max(lower, self.masters(self.player, trump));
+            for opponent in enum_iterator::all::<PlayerName>().filter(|&p| p != self.player) {
+                upper = cmp::min(upper, remaining - self.masters(opponent, trump));
+            }
+        }
+        Bounds { lower, upper }
+    }
+
+    /// Returns the number of cards in `suit` held by `player` which are higher
+    /// than every card in that suit held by another player.
+    fn masters(&self, player: PlayerName, suit: Suit) -> i32 {
+        let hand = self.hands[player as usize].as_u64();
+        let mut remaining = self.occupied() & suit_mask(suit);
+        let mut result = 0;
+        while remaining != 0 {
+            let top = 1 << (63 - remaining.leading_zeros());
+            if hand & top == 0 {
+                break;
+            }
+            remaining &= !top;
+            result += 1;
+        }
+        result
+    }
+
+    /// Returns the number of tricks `leader` can win immediately by leading
+    /// cards which are the highest remaining cards in their suit.
+    ///
+    /// If any other player holds a trump, only trump suit cards are counted,
+    /// since other suits could be ruffed.
+    fn quick_tricks(&self, leader: PlayerName) -> i32 {
+        let hand = self.hands[leader as usize];
+        let others_have_trump = self
+            .trump
+            .is_some_and(|trump| self.occupied() & !hand.as_u64() & suit_mask(trump) != 0);
+
+        let result = enum_iterator::all::<Suit>()
+            .filter(|&suit| !others_have_trump || Some(suit) == self.trump)
+            .map(|suit| self.masters(leader, suit))
+            .sum();
+        cmp::min(result, hand.len() as i32)
+    }
+}
+
+/// Returns the bits of all cards in `suit` within a hand's bitset
+/// representation.
+fn suit_mask(suit: Suit) -> u64 {
+    0x1FFF << (suit as usize * 13)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use data::play_phase_data::parse_deal;
+    use data::primitive::primitives::NOTATION_SUITS;
+    use data::round_data::RoundData;
+    use rand::prelude::SliceRandom;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use rules::rounds::{new_round, start_play_phase};
+
+    use super::*;
+
+    /// Creates a position with no programs from hands written in deal
+    /// notation.
+    fn position(deal: &str, trump: Option<Suit>, leader: PlayerName) -> PlayPhaseData {
+        let hands = parse_deal(deal).expect("Invalid deal");
+        let RoundData::ContractPhase(data) = new_round::deal(hands, trump, leader, HashMap::new())
+        else {
+            panic!("Expected ContractPhase");
+        };
+        start_play_phase::run(data)
+    }
+
+    /// Deals `size` random cards from `suits` to each player and then plays up
+    /// to three random cards, so the position can start in the middle of a
+    /// trick.
+    fn random_position(size: usize, suits: &[Suit], rng: &mut StdRng) -> PlayPhaseData {
+        let mut cards =
+            EnumSet::<Card>::all().iter().filter(|c| suits.contains(&c.suit())).collect::<Vec<_>>();
+        cards.shuffle(rng);
+        let mut chunks = cards.chunks_exact(size);
+        let mut hand = || chunks.next().expect("Not enough cards").iter().copied().collect();
+        let hands = data::play_phase_data::Hands::new(hand(), hand(), hand(), hand());
+        let trump = *[None, Some(Suit::Clubs), Some(Suit::Hearts), Some(Suit::Spades)]
+            .choose(rng)
+            .expect("Empty slice");
+        let leader = enum_iterator::all::<PlayerName>().nth(rng.gen_range(0..4)).expect("Leader");
+        let RoundData::ContractPhase(data) = new_round::deal(hands, trump, leader, HashMap::new())
+        else {
+            panic!("Expected ContractPhase");
+        };
+        let mut data = start_play_phase::run(data);
+        for _ in 0..rng.gen_range(0..4) {
+            let player = data.current_turn();
+            let cards = data.legal_actions(player).collect::<Vec<_>>();
+            data.execute_action(player, *cards.choose(rng).expect("No legal actions"));
+        }
+        data
+    }
+
+    /// Plain exhaustive minimax over the game rules, returning the number of
+    /// tricks `player` wins in total when they maximize it and every other
+    /// player minimizes it.
+    fn minimax(data: &PlayPhaseData, player: PlayerName) -> usize {
+        let Some(turn) = data.turn else {
+            return tricks::won(data, player);
+        };
+        let values = data.legal_actions(turn).map(|action| {
+            let mut child = data.clone();
+            child.execute_action(turn, action);
+            minimax(&child, player)
+        });
+        if turn == player { values.max() } else { values.min() }.expect("No legal actions")
+    }
+
+    fn assert_matches_minimax(size: usize, suits: &[Suit], positions: usize, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        for _ in 0..positions {
+            let data = random_position(size, suits, &mut rng);
+            let player =
+                enum_iterator::all::<PlayerName>().nth(rng.gen_range(0..4)).expect("Player");
+            let expected = minimax(&data, player);
+            assert_eq!(max_tricks(&data, player), expected, "{player:?} in {:?}", data.hands);
+
+            let (card, value) = DoubleDummySolver::new(&data, player).best_card();
+            let mut child = data.clone();
+            child.execute_action(data.current_turn(), PlayPhaseAction::PlayCard(card));
+            assert_eq!(tricks::won(&data, player) + value, expected);
+            assert_eq!(minimax(&child, player), expected, "Played {card:?} in {:?}", data.hands);
+        }
+    }
+
+    #[test]
+    fn matches_minimax_with_three_cards() {
+        assert_matches_minimax(3, &NOTATION_SUITS, 100, 1);
+    }
+
+    #[test]
+    fn matches_minimax_with_four_cards() {
+        assert_matches_minimax(4, &NOTATION_SUITS, 40, 2);
+    }
+
+    #[test]
+    fn matches_minimax_with_five_cards() {
+        // Fewer suits means players must follow more often, which keeps the
+        // exhaustive search fast enough
+        assert_matches_minimax(5, &[Suit::Spades, Suit::Hearts], 4, 3);
+    }
+
+    #[test]
+    fn solves_full_deals() {
+        let suits = "S:AKQJT98765432 / H:AKQJT98765432 / D:AKQJT98765432 / C:AKQJT98765432";
+        let data = position(suits, None, PlayerName::North);
+        assert_eq!(max_tricks(&data, PlayerName::North), 13);
+        assert_eq!(max_tricks(&data, PlayerName::User), 0);
+
+        // The user can ruff the opening lead and then draw every trump
+        let data = position(suits, Some(Suit::Diamonds), PlayerName::North);
+        assert_eq!(max_tricks(&data, PlayerName::User), 13);
+        assert_eq!(max_tricks(&data, PlayerName::North), 0);
+
+        // Twelve winners, but the opponents can always keep a spade to beat the
+        // two
+        let data = position(
+            "S:J987 H:JT9 D:JT9 C:JT9 / S:6543 H:876 D:876 C:876 / \
+             S:AKQ2 H:AKQ D:AKQ C:AKQ / S:T H:5432 D:5432 C:5432",
+            None,
+            PlayerName::User,
+        );
+        assert_eq!(max_tricks(&data, PlayerName::User), 12);
+    }
+
+    #[test]
+    fn counts_tricks_already_won() {
+        let mut data = position("S:2 H:A / S:3 H:2 / S:A H:3 / S:4 H:4", None, PlayerName::User);
+        for (player, card) in [
+            (PlayerName::User, "AS"),
+            (PlayerName::West, "4S"),
+            (PlayerName::North, "2S"),
+            (PlayerName::East, "3S"),
+        ] {
+            let card = card.parse().expect("Invalid card");
+            data.execute_action(player, PlayPhaseAction::PlayCard(card));
+        }
+        assert_eq!(max_tricks(&data, PlayerName::User), 1);
+        assert_eq!(max_tricks(&data, PlayerName::North), 1);
+    }
+
+    #[test]
+    fn table_key_uses_relative_ranks() {
+        let key = |deal: &str, leader: PlayerName| {
+            DoubleDummySolver::new(&position(deal, None, leader), PlayerName::User).table_key()
+        };
+        let deal = "S:K H:2 / S:Q H:3 / S:A H:4 / S:J H:5";
+        let key_for_deal = key(deal, PlayerName::User);
+        assert!(key("S:Q H:2 / S:J H:3 / S:K H:4 / S:T H:5", PlayerName::User) == key_for_deal);
+        assert!(key(deal, PlayerName::North) != key_for_deal);
+        for other in [
+            "S:Q H:2 / S:K H:3 / S:A H:4 / S:J H:5",
+            "S:J H:2 / S:Q H:3 / S:A H:4 / S:K H:5",
+            "S:K H:2 / S:J H:3 / S:A H:4 / S:Q H:5",
+            "S:K H:3 / S:Q H:2 / S:A H:4 / S:J H:5",
+        ] {
+            assert!(key(other, PlayerName::User) != key_for_deal, "{other}");
+        }
+    }
+
+    #[test]
+    fn prunes_equivalent_cards() {
+        let card = |s: &str| s.parse::<Card>().expect("Invalid card");
+
+        // The queen and jack are equivalent, but the ten separates the jack
+        // from the nine
+        let data = position("S:2 H:2 / S:3 H:3 / S:QJ9 / S:T H:4", None, PlayerName::User);
+        let solver = DoubleDummySolver::new(&data, PlayerName::User);
+        assert_eq!(solver.moves(PlayerName::User), vec![card("QS"), card("9S")]);
+
+        // Cards in completed tricks no longer separate anything
+        let mut data = position("S:2 H:2 / S:3 H:3 / S:QT9 / S:J H:4", None, PlayerName::West);
+        for (player, card) in [
+            (PlayerName::West, card("JS")),
+            (PlayerName::North, card("2S")),
+            (PlayerName::East, card("3S")),
+            (PlayerName::User, card("9S")),
+        ] {
+            data.execute_action(player, PlayPhaseAction::PlayCard(card));
+        }
+        let solver = DoubleDummySolver::new(&data, PlayerName::User);
+        assert_eq!(solver.moves(PlayerName::User), vec![card("QS")]);
+
+        // Cards in the current trick still do
+        let mut data = position("S:2 H:2 / S:3 H:3 / S:QT / S:J H:4", None, PlayerName::West);
+        data.execute_action(PlayerName::West, PlayPhaseAction::PlayCard(card("JS")));
+        data.execute_action(PlayerName::North, PlayPhaseAction::PlayCard(card("2S")));
+        data.execute_action(PlayerName::East, PlayPhaseAction::PlayCard(card("3S")));
+        let mut moves = DoubleDummySolver::new(&data, PlayerName::User).moves(PlayerName::User);
+        moves.sort();
+        assert_eq!(moves, vec![card("TS"), card("QS")]);
+    }
+
+    #[test]
+    fn bounds_quick_tricks() {
+        let deal = "S:QJ H:K D:2 / S:T9 H:Q D:3 / S:AK H:A D:A / S:87 H:J D:4";
+
+        // Every ace and king is a quick trick at notrump
+        let data = position(deal, None, PlayerName::User);
+        let solver = DoubleDummySolver::new(&data, PlayerName::User);
+        assert_eq!(solver.quick_tricks(PlayerName::User), 4);
+        let bounds = solver.trick_start_bounds(PlayerName::User, 4);
+        assert_eq!((bounds.lower, bounds.upper), (4, 4));
+        assert_eq!(max_tricks(&data, PlayerName::User), 4);
+
+        // Only master trumps count when an opponent could ruff
+        let data = position(deal, Some(Suit::Diamonds), PlayerName::User);
+        let solver = DoubleDummySolver::new(&data, PlayerName::User);
+        assert_eq!(solver.quick_tricks(PlayerName::User), 1);
+        let bounds = solver.trick_start_bounds(PlayerName::User, 4);
+        assert_eq!((bounds.lower, bounds.upper), (1, 4));
+        assert_eq!(max_tricks(&data, PlayerName::User), 4);
+
+        // An opponent's quick tricks bound the user's from above
+        let solver = DoubleDummySolver::new(&data, PlayerName::North);
+        let bounds = solver.trick_start_bounds(PlayerName::User, 4);
+        assert_eq!((bounds.lower, bounds.upper), (0, 3));
+        assert_eq!(max_tricks(&data, PlayerName::North), 0);
+    }
+}
@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use data::play_phase_data::PlayPhaseData;
-use data::primitive::primitives::PlayerName;
-use rules::rounds::tricks;
+use data::primitive::primitives::{Card, PlayerName, Rank};
+use enum_iterator::all;
+use enumset::EnumSet;
+use rules::rounds::{scoring, tricks};
 
 use crate::core::game_state_node::{GameStateNode, GameStatus};
 use crate::core::state_evaluator::StateEvaluator;
@@ -37,3 +39,103 @@ impl StateEvaluator<PlayPhaseData> for MaxTricksEvaluator {
         tricks::won(data, player) as i32
     }
 }
+
+/// Evaluates a position by the expected score for a player's contract.
+///
+/// Completed games are scored exactly. For games in progress, this estimates
+/// the probability of winning enough further tricks to make the contract and
+/// returns that probability multiplied by the value of the contract, so agents
+/// stop fighting for tricks once their contract is secured or hopeless.
+///
+/// The estimate works as follows:
+///
+/// ```text
+/// needed ← contract − tricks won
+/// if needed ≤ 0: return 1
+/// if needed > tricks remaining: return 0
+/// sure ← cards in hand which are the highest remaining card of their suit
+/// if sure ≥ needed: return 1
+/// q ← share of remaining high cards & trumps held, excluding sure cards
+/// return P(Binomial(tricks remaining − sure, q) ≥ needed − sure)
+/// ```
+pub struct ContractEvaluator;
+
+/// Additional strength given to each trump card when estimating the chance
+/// of winning a trick.
+const TRUMP_STRENGTH: u32 = 2;
+
+impl StateEvaluator<PlayPhaseData> for ContractEvaluator {
+    fn evaluate(&self, data: &PlayPhaseData, player: PlayerName) -> i32 {
+        match data.status() {
+            GameStatus::InProgress { .. } => {
+                let contract = data.contracts.contract_number(player);
+                let value = scoring::contract_value(contract).as_i32();
+                (contract_probability(data, player) * f64::from(value)).round() as i32
+            }
+            GameStatus::Completed { scores } => scores[&player],
+        }
+    }
+}
+
+/// Estimates the probability that `player` makes their contract from the
+/// current position.
+fn contract_probability(data: &PlayPhaseData, player: PlayerName) -> f64 {
+    let needed = data.contracts.contract_number(player).saturating_sub(tricks::won(data, player));
+    if needed == 0 {
+        return 1.0;
+    }
+
+    let hand = data.hands.hand(player);
+    let in_trick = data.current_trick.cards.iter().any(|played| played.played_by == player);
+    let remaining = hand.len() + usize::from(in_trick);
+    if needed > remaining {
+        return 0.0;
+    }
+
+    let remaining_cards =
+        all::<PlayerName>().fold(EnumSet::empty(), |cards, name| cards | data.hands.hand(name));
+    let sure = hand
+        .iter()
+        .filter(|&card| {
+            remaining_cards
+                .iter()
+                .all(|other| other.suit() != card.suit() || other.rank() <= card.rank())
+        })
+        .collect::<EnumSet<_>>();
+    if sure.len() >= needed {
+        return 1.0;
+    }
+
+    let own = (hand - sure).iter().map(|card| strength(data, card)).sum::<u32>();
+    let total = (remaining_cards - sure).iter().map(|card| strength(data, card)).sum::<u32>();
+    let share = f64::from(own + 1) / f64::from(total + 4);
+    binomial_tail(remaining - sure.len(), needed - sure.len(), share)
+}
+
+/// Rough measure of how likely a card is to win a trick, using standard high
+/// card points plus a bonus for trumps.
+fn strength(data: &PlayPhaseData, card: Card) -> u32 {
+    let points = match card.rank() {
+        Rank::Ace => 4,
+        Rank::King => 3,
+        Rank::Queen => 2,
+        Rank::Jack => 1,
+        _ => 0,
+    };
+    let trump = if data.trump == Some(card.suit()) { TRUMP_STRENGTH } else { 0 };
+    points + trump
+}
+
+/// Returns the probability of at least `k` successes in `n` independent trials
+/// which each succeed with probability `p`.
+fn binomial_tail(n: usize, k: usize, p: f64) -> f64 {
+    let mut coefficient = 1.0;
+    let mut result = 0.0;
+    for i in 0..=n {
+        if i >= k {
+            result += coefficient * p.powi(i as i32) * (1.0 - p).powi((n - i) as i32);
+        }
+        coefficient = coefficient * (n - i) as f64 / (i + 1) as f64;
+    }
+    result
+}
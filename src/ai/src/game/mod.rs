@@ -13,5 +13,9 @@
 // limitations under the License.
 
 pub mod agents;
+pub mod bidding;
 pub mod definitions;
+pub mod double_dummy;
 pub mod evaluators;
+pub mod predictors;
+pub mod rollout_policies;
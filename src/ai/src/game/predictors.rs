@@ -0,0 +1,161 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::iter;
+
+use data::play_phase_data::{Hands, PlayPhaseData};
+use data::primitive::primitives::{Card, PlayerName, Suit};
+use enumset::EnumSet;
+use rand::prelude::SliceRandom;
+use rand::Rng;
+use rules::rounds::{cards, tricks};
+
+use crate::core::game_state_node::GameStateNode;
+use crate::core::search_rng;
+
+/// Number of game states returned by the [information_set] predictor.
+pub const SAMPLE_COUNT: usize = 10;
+
+/// Number of times to attempt a random deal before giving up and falling back
+/// to the canonical game state.
+const MAX_DEAL_ATTEMPTS: usize = 100;
+
+/// A [StatePredictor] which only relies on information visible to the player
+/// whose turn it currently is.
+///
+/// Returns [SAMPLE_COUNT] game states in which the cards in the other three
+/// hands have been randomly redistributed, consistent with the number of cards
+/// in each hand and with the suits each player is known to be void in.
+///
+/// [StatePredictor]: crate::core::state_predictor::StatePredictor
+pub fn information_set(data: &PlayPhaseData) -> Box<dyn Iterator<Item = PlayPhaseData>> {
+    Box::new(determinizations(data).take(SAMPLE_COUNT))
+}
+
+/// A [StatePredictor] which returns an unlimited number of game states
+/// sampled in the same way as [information_set].
+///
+/// The information set is computed once, so drawing each additional state
+/// only requires a new random deal.
+///
+/// [StatePredictor]: crate::core::state_predictor::StatePredictor
+pub fn determinizations(data: &PlayPhaseData) -> Box<dyn Iterator<Item = PlayPhaseData>> {
+    let information_set = InformationSet::new(data, data.current_turn());
+    Box::new(iter::repeat_with(move || search_rng::with(|rng| information_set.sample(rng))))
+}
+
+/// The game states which are indistinguishable from a given state from the
+/// perspective of an `observer`, i.e. those with the same visible cards and
+/// play history.
+pub struct InformationSet {
+    data: PlayPhaseData,
+    /// Players whose hands are hidden from the observer
+    others: Vec<PlayerName>,
+    /// Cards held by the other players
+    unseen: Vec<Card>,
+    /// Suits each player is known to be void in, per [voids]
+    voids: HashSet<(PlayerName, Suit)>,
+}
+
+impl InformationSet {
+    pub fn new(data: &PlayPhaseData, observer: PlayerName) -> Self {
+        let others =
+            enum_iterator::all::<PlayerName>().filter(|&p| p != observer).collect::<Vec<_>>();
+        let unseen = others.iter().flat_map(|&p| data.hands.hand(p)).collect();
+        Self { data: data.make_copy(), others, unseen, voids: voids(data) }
+    }
+
+    /// Returns a copy of the game state in which the cards not visible to the
+    /// observer have been randomly dealt to the other players.
+    ///
+    /// The resulting deal preserves the size of each hand and never gives a
+    /// player a card in a suit they are known to be void in.
+    pub fn sample(&self, rng: &mut impl Rng) -> PlayPhaseData {
+        let mut result = self.data.make_copy();
+        for _ in 0..MAX_DEAL_ATTEMPTS {
+            if let Some(hands) = self.deal(rng) {
+                result.hands = hands;
+                return result;
+            }
+        }
+
+        // The canonical deal always satisfies every constraint, so we can fall
+        // back to it if sampling repeatedly fails.
+        result
+    }
+
+    /// Attempts to randomly deal the unseen cards back to the other players.
+    /// Returns None if the deal could not be completed without violating a
+    /// void constraint.
+    fn deal(&self, rng: &mut impl Rng) -> Option<Hands> {
+        let eligible =
+            |player: PlayerName, card: Card| !self.voids.contains(&(player, card.suit()));
+
+        let mut unseen = self.unseen.clone();
+        unseen.shuffle(rng);
+        // Deal the most constrained cards first. This is a stable sort, so cards
+        // with the same number of candidates remain shuffled.
+        unseen.sort_by_key(|&card| self.others.iter().filter(|&&p| eligible(p, card)).count());
+
+        let mut remaining = self
+            .others
+            .iter()
+            .map(|&p| (p, self.data.hands.hand(p).len()))
+            .collect::<HashMap<_, _>>();
+        let mut hands = self.data.hands.clone();
+        for &player in &self.others {
+            *hands.hand_mut(player) = EnumSet::empty();
+        }
+
+        for card in unseen {
+            let candidates = self
+                .others
+                .iter()
+                .copied()
+                .filter(|&p| remaining[&p] > 0 && eligible(p, card))
+                .collect::<Vec<_>>();
+            // Weighting by remaining hand size makes every consistent deal
+            // roughly equally likely.
+            let &player = candidates.choose_weighted(rng, |p| remaining[p]).ok()?;
+            hands.hand_mut(player).insert(card);
+            *remaining.get_mut(&player).expect("Player not found") -= 1;
+        }
+
+        Some(hands)
+    }
+}
+
+/// Returns the set of suits each player is known to hold no cards in, because
+/// they previously failed to follow suit while they were required to do so.
+pub fn voids(data: &PlayPhaseData) -> HashSet<(PlayerName, Suit)> {
+    let mut result = HashSet::new();
+    let all_tricks = data
+        .completed_tricks
+        .iter()
+        .map(|completed| &completed.trick)
+        .chain(iter::once(&data.current_trick));
+    for (number, trick) in all_tricks.enumerate() {
+        let Some(suit) = tricks::suit(trick) else {
+            continue;
+        };
+        for played in &trick.cards {
+            if played.card.suit() != suit && cards::must_follow_suit(data, played.played_by, number)
+            {
+                result.insert((played.played_by, suit));
+            }
+        }
+    }
+    result
+}
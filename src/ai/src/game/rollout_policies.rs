@@ -0,0 +1,73 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::play_phase_data::{PlayPhaseAction, PlayPhaseData, PlayedCard};
+use data::primitive::primitives::{Card, PlayerName};
+use rand::prelude::SliceRandom;
+use rand::RngCore;
+use rules::rounds::tricks;
+
+use crate::core::game_state_node::GameStateNode;
+use crate::monte_carlo::rollout_policy::{RandomRollout, RolloutPolicy};
+
+/// Rollout policy for the play phase which follows simple trick-taking
+/// heuristics:
+///
+/// - When leading a trick, play a random card.
+/// - When following, play the cheapest card which would currently win the
+///   trick.
+/// - If no card can win the trick, play the lowest card available.
+///
+/// Programs are never activated, since activating them at random is much more
+/// likely to waste them than to help.
+pub struct TrickTakingRollout;
+
+impl RolloutPolicy<PlayPhaseData> for TrickTakingRollout {
+    fn select_action(
+        &self,
+        data: &PlayPhaseData,
+        player: PlayerName,
+        rng: &mut dyn RngCore,
+    ) -> PlayPhaseAction {
+        let cards = data
+            .legal_actions(player)
+            .filter_map(|action| match action {
+                PlayPhaseAction::PlayCard(card) => Some(card),
+                PlayPhaseAction::ActivateProgram(_) => None,
+            })
+            .collect::<Vec<_>>();
+        if cards.is_empty() {
+            return RandomRollout.select_action(data, player, rng);
+        }
+
+        let Some(suit) = tricks::suit(&data.current_trick) else {
+            return PlayPhaseAction::PlayCard(*cards.choose(rng).expect("No cards found"));
+        };
+        let lowest = |candidates: &mut dyn Iterator<Item = Card>| {
+            candidates.min_by(|&a, &b| tricks::card_ordering(data, suit, a, b))
+        };
+        let card = lowest(&mut cards.iter().copied().filter(|&card| wins(data, player, card)))
+            .or_else(|| lowest(&mut cards.iter().copied()))
+            .expect("No cards found");
+        PlayPhaseAction::PlayCard(card)
+    }
+}
+
+/// Returns true if `player` would currently be winning the trick after
+/// playing `card` to it.
+fn wins(data: &PlayPhaseData, player: PlayerName, card: Card) -> bool {
+    let mut trick = data.current_trick.clone();
+    trick.cards.push(PlayedCard { played_by: player, card });
+    tricks::winner(data, &trick) == player
+}
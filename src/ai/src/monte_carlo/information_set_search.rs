@@ -0,0 +1,334 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An implementation of Single-Observer Information Set Monte Carlo Tree
+//! Search.
+//!
+//! This implementation is based on the pseudocode given in "Information Set
+//! Monte Carlo Tree Search" by Cowling, Powley and Whitehouse in IEEE
+//! Transactions on Computational Intelligence and AI in Games, Vol. 4, No. 2,
+//! June 2012.
+
+use std::collections::HashSet;
+use std::time::Instant;
+
+use petgraph::prelude::{EdgeRef, NodeIndex};
+use petgraph::{Direction, Graph};
+use rand::prelude::IteratorRandom;
+use tracing::debug;
+
+use crate::core::agent::{Agent, AgentConfig};
+use crate::core::game_state_node::{GameStateNode, GameStatus};
+use crate::core::search_rng;
+use crate::core::state_evaluator::StateEvaluator;
+use crate::core::state_predictor::StatePredictor;
+use crate::monte_carlo::child_score::{ChildScoreAlgorithm, SelectionMode};
+
+#[derive(Debug, Clone)]
+struct SearchNode<TState: GameStateNode> {
+    /// Player who acted to create this node
+    pub player: TState::PlayerName,
+    /// Q(v): Total reward of all playouts that passed through this node
+    pub total_reward: f64,
+    /// N(v): Visit count for this node
+    pub visit_count: u32,
+    /// N'(v): Number of times this node was available for selection from its
+    /// parent
+    pub availability_count: u32,
+}
+
+struct SearchEdge<TState: GameStateNode> {
+    pub action: TState::Action,
+}
+
+type SearchGraph<TState> = Graph<SearchNode<TState>, SearchEdge<TState>>;
+
+/// SO-ISMCTS search agent.
+///
+/// Unlike [MonteCarloAlgorithm], which searches the tree of a single
+/// perfect-information game state, this searches a tree of *information sets*
+/// from the perspective of the current player. Each iteration of the search
+/// picks a new determinization of the hidden game state via the
+/// [StatePredictor], and descends the tree using only those actions which are
+/// legal in that determinization. Nodes are keyed by action, so statistics are
+/// shared across all determinizations.
+///
+/// Because a child node is not available for selection in every
+/// determinization, the child score uses the number of times each child was
+/// *available* in place of the parent visit count.
+///
+/// The search always runs on a single thread, [AgentConfig::threads] is
+/// ignored.
+///
+/// Pseudocode:
+/// ```text
+/// 𝐟𝐮𝐧𝐜𝐭𝐢𝐨𝐧 SO-ISMCTS([s₀]¹)
+///   create root node v₀ with information set [s₀]¹
+///   𝐰𝐡𝐢𝐥𝐞 within computational budget 𝐝𝐨
+///     choose d₀ ∈ [s₀]¹ at random
+///     (v, d) ← SELECT(v₀, d₀)
+///     𝐢𝐟 u(v, d) ≠ ∅ 𝐭𝐡𝐞𝐧
+///       (v, d) ← EXPAND(v, d)
+///     ∆ ← SIMULATE(d)
+///     BACKPROPAGATE(∆, v)
+///   𝐫𝐞𝐭𝐮𝐫𝐧 𝒂(c) where c ∈ argmax N(c) for c ∈ c(v₀)
+/// ```
+///
+/// [MonteCarloAlgorithm]: crate::monte_carlo::monte_carlo_search::MonteCarloAlgorithm
+pub struct InformationSetMonteCarloAgent<TScoreAlgorithm, TEvaluator, TNode>
+where
+    TScoreAlgorithm: ChildScoreAlgorithm,
+    TEvaluator: StateEvaluator<TNode>,
+    TNode: GameStateNode + 'static,
+{
+    /// Name of this agent, used for debugging.
+    pub name: &'static str,
+    /// Produces determinizations of the hidden game state. The predictor is
+    /// invoked once per search and one state is drawn from it on each
+    /// iteration, so it should not run out of states before the search ends.
+    pub predictor: StatePredictor<TNode>,
+    pub child_score_algorithm: TScoreAlgorithm,
+    /// Evaluator for newly-expanded nodes, typically a random playout.
+    pub evaluator: TEvaluator,
+    pub max_iterations: Option<u32>,
+}
+
+impl<TScoreAlgorithm, TEvaluator, TNode> Agent<TNode>
+    for InformationSetMonteCarloAgent<TScoreAlgorithm, TEvaluator, TNode>
+where
+    TScoreAlgorithm: ChildScoreAlgorithm,
+    TEvaluator: StateEvaluator<TNode>,
+    TNode: GameStateNode + 'static,
+{
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn pick_action(&self, config: AgentConfig, node: &TNode) -> TNode::Action {
+        search_rng::seed(config.seed);
+        self.run_search(
+            |i| {
+                (i % 100 == 0 && config.deadline < Instant::now())
+                    || self.max_iterations.is_some_and(|max| i > max)
+            },
+            node,
+        )
+    }
+}
+
+impl<TScoreAlgorithm, TEvaluator, TNode>
+    InformationSetMonteCarloAgent<TScoreAlgorithm, TEvaluator, TNode>
+where
+    TScoreAlgorithm: ChildScoreAlgorithm,
+    TEvaluator: StateEvaluator<TNode>,
+    TNode: GameStateNode + 'static,
+{
+    pub fn run_search(&self, should_halt: impl Fn(u32) -> bool, node: &TNode) -> TNode::Action {
+        let player = node.current_turn();
+        let mut graph = SearchGraph::new();
+        let root = graph.add_node(SearchNode {
+            player,
+            total_reward: 0.0,
+            visit_count: 1,
+            availability_count: 1,
+        });
+        let mut determinizations = (self.predictor)(node);
+        let mut i = 0;
+        while !should_halt(i) {
+            let mut game = determinizations.next().expect("No states predicted");
+            let leaf = self.tree_policy(&mut graph, &mut game, root);
+            let reward = f64::from(self.evaluator.evaluate(&game, player));
+            Self::backup(&mut graph, player, leaf, reward);
+            i += 1;
+        }
+
+        let legal = node.legal_actions(player).collect::<HashSet<_>>();
+        self.log_results(&graph, root, &legal);
+        graph
+            .edges(root)
+            .filter(|edge| legal.contains(&edge.weight().action))
+            .max_by_key(|edge| graph[edge.target()].visit_count)
+            .map(|edge| edge.weight().action)
+            .expect("No children found")
+    }
+
+    fn log_results(
+        &self,
+        graph: &SearchGraph<TNode>,
+        root: NodeIndex,
+        legal: &HashSet<TNode::Action>,
+    ) {
+        for edge in graph.edges(root).filter(|edge| legal.contains(&edge.weight().action)) {
+            let child = &graph[edge.target()];
+            debug!(
+                "Action: {:?} at {:?} with {:?} visits",
+                self.score(child, SelectionMode::Best),
+                edge.weight().action,
+                child.visit_count
+            );
+        }
+    }
+
+    /// Descends the tree from `node`, restricted to the actions which are
+    /// legal in the current determinization `game`, until reaching either a
+    /// terminal state or a node with an action that has not yet been
+    /// explored. In the latter case, that action is applied and the resulting
+    /// new child is returned.
+    ///
+    /// Mutates the provided game state to represent the state at the returned
+    /// node.
+    ///
+    /// Pseudocode:
+    /// ```text
+    /// 𝐟𝐮𝐧𝐜𝐭𝐢𝐨𝐧 SELECT(v, d)
+    ///   𝐰𝐡𝐢𝐥𝐞 d is nonterminal and u(v, d) = ∅ 𝐝𝐨
+    ///     c ← argmax(
+    ///       c ∈ c(v, d):
+    ///       Q(c) / N(c) + k * √ [ ln(N'(c)) / N(c) ]
+    ///     )
+    ///     v ← c; d ← f(d, 𝒂(c))
+    ///   𝐫𝐞𝐭𝐮𝐫𝐧 (v, d)
+    /// ```
+    fn tree_policy(
+        &self,
+        graph: &mut SearchGraph<TNode>,
+        game: &mut TNode,
+        mut node: NodeIndex,
+    ) -> NodeIndex {
+        while let GameStatus::InProgress { current_turn } = game.status() {
+            let actions = game.legal_actions(current_turn).collect::<Vec<_>>();
+            let legal = actions.iter().copied().collect::<HashSet<_>>();
+            Self::mark_available(graph, node, &legal);
+
+            let explored = graph.edges(node).map(|e| e.weight().action).collect::<HashSet<_>>();
+            let untried = actions.iter().filter(|a| !explored.contains(a));
+            if let Some(&action) = search_rng::with(|rng| untried.choose(rng)) {
+                return Self::expand(graph, game, current_turn, node, action);
+            }
+
+            let (action, best) = self.best_child(graph, node, &legal, SelectionMode::Exploration);
+            game.execute_action(current_turn, action);
+            node = best;
+        }
+        node
+    }
+
+    /// Increments the availability count for each child of `node` which is
+    /// reachable via one of the `legal` actions.
+    fn mark_available(
+        graph: &mut SearchGraph<TNode>,
+        node: NodeIndex,
+        legal: &HashSet<TNode::Action>,
+    ) {
+        let available = graph
+            .edges(node)
+            .filter(|edge| legal.contains(&edge.weight().action))
+            .map(|edge| edge.target())
+            .collect::<Vec<_>>();
+        for child in available {
+            graph[child].availability_count += 1;
+        }
+    }
+
+    /// Adds a new child for `action` to `source` and applies the action to the
+    /// provided game state.
+    ///
+    /// Pseudocode:
+    /// ```text
+    /// 𝐟𝐮𝐧𝐜𝐭𝐢𝐨𝐧 EXPAND(v, d)
+    ///   choose 𝒂 from u(v, d) uniformly at random
+    ///   add a child w to v with 𝒂(w) = 𝒂
+    ///   v ← w; d ← f(d, 𝒂)
+    ///   𝐫𝐞𝐭𝐮𝐫𝐧 (v, d)
+    /// ```
+    fn expand(
+        graph: &mut SearchGraph<TNode>,
+        game: &mut TNode,
+        player: TNode::PlayerName,
+        source: NodeIndex,
+        action: TNode::Action,
+    ) -> NodeIndex {
+        game.execute_action(player, action);
+        let target = graph.add_node(SearchNode {
+            player,
+            total_reward: 0.0,
+            visit_count: 0,
+            availability_count: 1,
+        });
+        graph.add_edge(source, target, SearchEdge { action });
+        target
+    }
+
+    /// Picks the highest-scoring child of `node` among those reachable via
+    /// one of the `legal` actions, returning its associated action and node
+    /// identifier.
+    fn best_child(
+        &self,
+        graph: &SearchGraph<TNode>,
+        node: NodeIndex,
+        legal: &HashSet<TNode::Action>,
+        selection_mode: SelectionMode,
+    ) -> (TNode::Action, NodeIndex) {
+        let edge = graph
+            .edges(node)
+            .filter(|edge| legal.contains(&edge.weight().action))
+            .map(|edge| (edge, self.score(&graph[edge.target()], selection_mode)))
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .map(|(edge, _)| edge)
+            .expect("No children found");
+        (edge.weight().action, edge.target())
+    }
+
+    fn score(&self, child: &SearchNode<TNode>, selection_mode: SelectionMode) -> f64 {
+        // This can technically panic when invoked from root with a very small
+        // simulation count, so don't do that :)
+        assert_ne!(child.visit_count, 0);
+        self.child_score_algorithm.score(
+            f64::from(child.availability_count),
+            f64::from(child.visit_count),
+            child.total_reward,
+            selection_mode,
+        )
+    }
+
+    /// Walks back up the tree from `node`, adding the resulting reward value
+    /// to each node.
+    ///
+    /// Pseudocode:
+    /// ```text
+    /// 𝐟𝐮𝐧𝐜𝐭𝐢𝐨𝐧 BACKPROPAGATE(∆, v)
+    ///   𝐰𝐡𝐢𝐥𝐞 v is not null 𝐝𝐨
+    ///     N(v) ← N(v) + 1
+    ///     Q(v) ← Q(v) + ∆(v, p)
+    ///     v ← parent of v
+    /// ```
+    fn backup(
+        graph: &mut SearchGraph<TNode>,
+        maximizing_player: TNode::PlayerName,
+        mut node: NodeIndex,
+        reward: f64,
+    ) {
+        loop {
+            let weight = graph.node_weight_mut(node).expect("Node not found");
+            weight.visit_count += 1;
+            weight.total_reward +=
+                if weight.player == maximizing_player { reward } else { -reward };
+
+            node = match graph.neighbors_directed(node, Direction::Incoming).next() {
+                Some(n) => n,
+                _ => return,
+            };
+        }
+    }
+}
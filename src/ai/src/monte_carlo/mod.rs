@@ -13,5 +13,7 @@
 // limitations under the License.
 
 pub mod child_score;
+pub mod information_set_search;
 pub mod monte_carlo_search;
+pub mod rollout_policy;
 pub mod uct1;
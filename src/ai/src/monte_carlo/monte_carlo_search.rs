@@ -18,57 +18,64 @@
 //! Carlo Tree Search Methods" by Browne et al. in IEEE Transactions on
 //! Computational Intelligence and AI in Games, Vol. 4, No. 1, March 2012.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Mutex;
 use std::time::Instant;
 
 use petgraph::prelude::{EdgeRef, NodeIndex};
-use petgraph::{Direction, Graph};
-use rand::prelude::IteratorRandom;
+use petgraph::Graph;
+use rayon::prelude::*;
 use tracing::debug;
 
 use crate::core::agent::AgentConfig;
 use crate::core::game_state_node::{GameStateNode, GameStatus};
+use crate::core::search_rng;
 use crate::core::selection_algorithm::SelectionAlgorithm;
 use crate::core::state_evaluator::StateEvaluator;
 use crate::monte_carlo::child_score::{ChildScoreAlgorithm, SelectionMode};
+use crate::monte_carlo::rollout_policy::RolloutPolicy;
 
-/// Plays out a game using random moves until a terminal state is reached, then
-/// evaluates the result using the provided state evaluator.
+/// Plays out a game using actions selected by a [RolloutPolicy] until a
+/// terminal state is reached, then evaluates the result using the provided
+/// state evaluator.
 ///
 /// Pseudocode:
 /// ```text
 /// 𝐟𝐮𝐧𝐜𝐭𝐢𝐨𝐧 DEFAULTPOLICY(s)
 ///   𝐰𝐡𝐢𝐥𝐞 s is non-terminal 𝐝𝐨
-///     choose 𝒂 ∈ A(s) uniformly at random
+///     choose 𝒂 ∈ A(s) according to the rollout policy
 ///     s ← f(s,𝒂)
 ///   𝐫𝐞𝐭𝐮𝐫𝐧 reward for state s
 /// ```
-pub struct RandomPlayoutEvaluator<TState: GameStateNode + Send, TEvaluator: StateEvaluator<TState>>
+pub struct PlayoutEvaluator<TState, TEvaluator, TPolicy>
+where
+    TState: GameStateNode,
+    TEvaluator: StateEvaluator<TState>,
+    TPolicy: RolloutPolicy<TState>,
 {
     pub evaluator: TEvaluator,
+    pub policy: TPolicy,
     pub phantom_data: PhantomData<TState>,
 }
 
-impl<TState: GameStateNode + Send, TEvaluator: StateEvaluator<TState>> StateEvaluator<TState>
-    for RandomPlayoutEvaluator<TState, TEvaluator>
+impl<TState, TEvaluator, TPolicy> StateEvaluator<TState>
+    for PlayoutEvaluator<TState, TEvaluator, TPolicy>
+where
+    TState: GameStateNode,
+    TEvaluator: StateEvaluator<TState>,
+    TPolicy: RolloutPolicy<TState>,
 {
     fn evaluate(&self, input: &TState, player: TState::PlayerName) -> i32 {
         let mut game = input.make_copy();
-        loop {
-            match game.status() {
-                GameStatus::Completed { .. } => {
-                    return self.evaluator.evaluate(&game, player);
-                }
-                GameStatus::InProgress { current_turn } => {
-                    let action = game
-                        .legal_actions(current_turn)
-                        .choose(&mut rand::thread_rng())
-                        .expect("No actions found");
-                    game.execute_action(current_turn, action);
-                }
+        search_rng::with(|rng| {
+            while let GameStatus::InProgress { current_turn } = game.status() {
+                let action = self.policy.select_action(&game, current_turn, rng);
+                game.execute_action(current_turn, action);
             }
-        }
+        });
+        self.evaluator.evaluate(&game, player)
     }
 }
 
@@ -88,21 +95,32 @@ struct SearchEdge<TState: GameStateNode> {
 
 type SearchGraph<TState> = Graph<SearchNode<TState>, SearchEdge<TState>>;
 
+/// Previously-expanded nodes, keyed by [GameStateNode::zobrist_hash] and by
+/// the player who acted to create the node.
+type Transpositions<TState> = HashMap<(u64, <TState as GameStateNode>::PlayerName), NodeIndex>;
+
 /// Monte Carlo search algorithm.
 ///
 /// Monte carlo tree search operates over a tree of game state nodes
 /// connected by game actions. The search follows these three steps
 /// repeatedly:
 ///
-/// 1) **Tree Policy:** Find a node in the tree which has not previously
-/// been explored. The UCT algorithm is one mathematical heuristic
-/// for how to prioritize nodes to explore.
+/// 1) **Tree Policy:** Find a node in the tree which has not previously been
+///    explored. The UCT algorithm is one mathematical heuristic for how to
+///    prioritize nodes to explore.
+///
+/// 2) **Default Policy:** Score this node to determine its reward value (∆),
+///    typically by playing random moves until the game terminates.
 ///
-/// 2) **Default Policy:** Score this node to determine its reward value
-/// (∆), typically by playing random moves until the game terminates.
+/// 3) **Backpropagation:** Walk back up the tree, adding the resulting reward
+///    value to each parent node.
 ///
-/// 3) **Backpropagation:** Walk back up the tree, adding the resulting
-/// reward value to each parent node.
+/// If the game supports [GameStateNode::zobrist_hash], game states reached via
+/// different action sequences share a single node, so the search operates over
+/// a directed acyclic graph rather than a tree.
+///
+/// When [AgentConfig::threads] is greater than 1, the search is run in parallel
+/// on the rayon thread pool as specified by [Parallelism].
 ///
 /// Pseudocode:
 /// ```text
@@ -116,7 +134,32 @@ type SearchGraph<TState> = Graph<SearchNode<TState>, SearchEdge<TState>>;
 /// ```
 pub struct MonteCarloAlgorithm<TScoreAlgorithm: ChildScoreAlgorithm> {
     pub child_score_algorithm: TScoreAlgorithm,
+    /// Maximum number of search iterations. For root-parallel searches, this
+    /// limit applies to each thread individually.
     pub max_iterations: Option<u32>,
+    pub parallelism: Parallelism,
+}
+
+/// Strategy for running a [MonteCarloAlgorithm] search on multiple threads.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Parallelism {
+    /// Each thread builds its own independent search tree. Once all searches
+    /// complete, visit counts for each action at the root are summed and the
+    /// most-visited action is selected.
+    Root,
+    /// All threads share a single search tree, which is locked during the
+    /// tree policy and backpropagation steps. Playouts run without the lock.
+    ///
+    /// While a playout is in progress, each node on its path is given a
+    /// *virtual loss* (an extra visit with no reward) to discourage other
+    /// threads from selecting the same path.
+    Tree,
+}
+
+/// Search tree state shared between threads in a [Parallelism::Tree] search.
+struct SharedTree<TState: GameStateNode> {
+    graph: SearchGraph<TState>,
+    transpositions: Transpositions<TState>,
 }
 
 impl<TScoreAlgorithm: ChildScoreAlgorithm> SelectionAlgorithm
@@ -133,15 +176,29 @@ impl<TScoreAlgorithm: ChildScoreAlgorithm> SelectionAlgorithm
         TStateNode: GameStateNode,
         TEvaluator: StateEvaluator<TStateNode>,
     {
-        self.run_search(
-            |i| {
-                (i % 100 == 0 && config.deadline < Instant::now())
-                    || self.max_iterations.map_or(false, |max| i > max)
-            },
-            node,
-            evaluator,
-            player,
-        )
+        let should_halt = |i| {
+            (i % 100 == 0 && config.deadline < Instant::now())
+                || self.max_iterations.is_some_and(|max| i > max)
+        };
+        match self.parallelism {
+            _ if config.threads <= 1 => self.run_search(should_halt, node, evaluator, player),
+            Parallelism::Root => self.run_root_parallel(
+                config.threads,
+                config.seed,
+                should_halt,
+                node,
+                evaluator,
+                player,
+            ),
+            Parallelism::Tree => self.run_tree_parallel(
+                config.threads,
+                config.seed,
+                should_halt,
+                node,
+                evaluator,
+                player,
+            ),
+        }
     }
 }
 
@@ -153,28 +210,149 @@ impl<TScoreAlgorithm: ChildScoreAlgorithm> MonteCarloAlgorithm<TScoreAlgorithm>
         evaluator: &TEvaluator,
         player: TStateNode::PlayerName,
     ) -> TStateNode::Action {
+        let (graph, root) = self.build_tree(should_halt, node, evaluator, player);
+        let (action, _) = self.best_child(
+            &graph,
+            root,
+            node.legal_actions(player).collect(),
+            SelectionMode::Best,
+        );
+
+        self.log_results(node, player, &graph, root);
+        action
+    }
+
+    /// Runs a [Parallelism::Root] search with `threads` independent search
+    /// trees. Each worker's random number generator is seeded from `seed`.
+    pub fn run_root_parallel<TStateNode, TEvaluator>(
+        &self,
+        threads: usize,
+        seed: Option<u64>,
+        should_halt: impl Fn(u32) -> bool + Sync,
+        node: &TStateNode,
+        evaluator: &TEvaluator,
+        player: TStateNode::PlayerName,
+    ) -> TStateNode::Action
+    where
+        TStateNode: GameStateNode,
+        TEvaluator: StateEvaluator<TStateNode>,
+    {
+        let trees = (0..threads)
+            .into_par_iter()
+            .map(|i| {
+                search_rng::seed_worker(seed, i);
+                let (graph, root) = self.build_tree(&should_halt, node, evaluator, player);
+                graph
+                    .edges(root)
+                    .map(|edge| (edge.weight().action, graph[edge.target()].visit_count))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut visits = HashMap::new();
+        for (action, count) in trees.into_iter().flatten() {
+            *visits.entry(action).or_insert(0) += count;
+        }
+        let (action, count) = node
+            .legal_actions(player)
+            .filter_map(|action| visits.get(&action).map(|&count| (action, count)))
+            .max_by_key(|&(_, count)| count)
+            .expect("No children found");
+        debug!("Action {:?} with {:?} visits across {:?} trees", action, count, threads);
+        action
+    }
+
+    /// Runs a [Parallelism::Tree] search with `threads` threads sharing a
+    /// single search tree.
+    pub fn run_tree_parallel<TStateNode, TEvaluator>(
+        &self,
+        threads: usize,
+        seed: Option<u64>,
+        should_halt: impl Fn(u32) -> bool + Sync,
+        node: &TStateNode,
+        evaluator: &TEvaluator,
+        player: TStateNode::PlayerName,
+    ) -> TStateNode::Action
+    where
+        TStateNode: GameStateNode,
+        TEvaluator: StateEvaluator<TStateNode>,
+    {
         let mut graph = SearchGraph::new();
         let root = graph.add_node(SearchNode { total_reward: 0.0, visit_count: 1, player });
-        let mut i = 0;
-        while !should_halt(i) {
-            let mut game = node.make_copy();
-            let node = self.tree_policy(&mut graph, &mut game, root);
-            let reward = f64::from(evaluator.evaluate(&game, player));
-            Self::backup(&mut graph, player, node, reward);
-            i += 1;
-        }
+        let transpositions = Transpositions::<TStateNode>::new();
+        let tree = Mutex::new(SharedTree { graph, transpositions });
+        let iterations = AtomicU32::new(0);
+        let halted = AtomicBool::new(false);
+
+        rayon::scope(|scope| {
+            for i in 0..threads {
+                let (tree, iterations, halted, should_halt) =
+                    (&tree, &iterations, &halted, &should_halt);
+                scope.spawn(move |_| {
+                    search_rng::seed_worker(seed, i);
+                    let mut path = vec![];
+                    while !halted.load(Ordering::Relaxed) {
+                        if should_halt(iterations.fetch_add(1, Ordering::Relaxed)) {
+                            halted.store(true, Ordering::Relaxed);
+                            break;
+                        }
+
+                        let mut game = node.make_copy();
+                        path.clear();
+                        path.push(root);
+                        {
+                            let mut tree = tree.lock().expect("Search tree lock poisoned");
+                            let SharedTree { graph, transpositions } = &mut *tree;
+                            self.tree_policy(graph, transpositions, &mut game, &mut path);
+                            Self::apply_virtual_loss(graph, &path, true);
+                        }
 
+                        let reward = f64::from(evaluator.evaluate(&game, player));
+                        let mut tree = tree.lock().expect("Search tree lock poisoned");
+                        Self::apply_virtual_loss(&mut tree.graph, &path, false);
+                        Self::backup(&mut tree.graph, player, &path, reward);
+                    }
+                });
+            }
+        });
+
+        let graph = tree.into_inner().expect("Search tree lock poisoned").graph;
         let (action, _) = self.best_child(
             &graph,
             root,
             node.legal_actions(player).collect(),
             SelectionMode::Best,
         );
-
         self.log_results(node, player, &graph, root);
         action
     }
 
+    /// Runs the search loop on the current thread until `should_halt` returns
+    /// true, returning the resulting search graph and its root node.
+    fn build_tree<TStateNode: GameStateNode, TEvaluator: StateEvaluator<TStateNode>>(
+        &self,
+        should_halt: impl Fn(u32) -> bool,
+        node: &TStateNode,
+        evaluator: &TEvaluator,
+        player: TStateNode::PlayerName,
+    ) -> (SearchGraph<TStateNode>, NodeIndex) {
+        let mut graph = SearchGraph::new();
+        let mut transpositions = Transpositions::<TStateNode>::new();
+        let root = graph.add_node(SearchNode { total_reward: 0.0, visit_count: 1, player });
+        let mut path = vec![];
+        let mut i = 0;
+        while !should_halt(i) {
+            let mut game = node.make_copy();
+            path.clear();
+            path.push(root);
+            self.tree_policy(&mut graph, &mut transpositions, &mut game, &mut path);
+            let reward = f64::from(evaluator.evaluate(&game, player));
+            Self::backup(&mut graph, player, &path, reward);
+            i += 1;
+        }
+        (graph, root)
+    }
+
     fn log_results<TStateNode: GameStateNode>(
         &self,
         node: &TStateNode,
@@ -222,6 +400,10 @@ impl<TScoreAlgorithm: ChildScoreAlgorithm> MonteCarloAlgorithm<TScoreAlgorithm>
     /// Mutates the provided [GameState] to represent the game state at the
     /// returned node.
     ///
+    /// Nodes are appended to `path` as they are visited, so that the final
+    /// element of `path` is the returned node. Because transposed nodes can
+    /// have multiple parents, this path is required for backpropagation.
+    ///
     /// Cᵖ is the exploration constant, Cᵖ = 1/√2 was suggested by Kocsis and
     /// Szepesvári as a good choice.
     ///
@@ -238,21 +420,30 @@ impl<TScoreAlgorithm: ChildScoreAlgorithm> MonteCarloAlgorithm<TScoreAlgorithm>
     fn tree_policy<TState: GameStateNode>(
         &self,
         graph: &mut SearchGraph<TState>,
+        transpositions: &mut Transpositions<TState>,
         game: &mut TState,
-        mut node: NodeIndex,
+        path: &mut Vec<NodeIndex>,
     ) -> NodeIndex {
+        let mut node = *path.last().expect("Path was empty");
         while let GameStatus::InProgress { current_turn } = game.status() {
-            let actions = game.legal_actions(current_turn).collect::<HashSet<_>>();
+            let actions = game.legal_actions(current_turn).collect::<Vec<_>>();
             let explored = graph.edges(node).map(|e| e.weight().action).collect::<HashSet<_>>();
             if let Some(action) = actions.iter().find(|a| !explored.contains(a)) {
                 // An action exists which has not yet been tried
-                return Self::expand(graph, game, current_turn, node, *action);
+                let child = Self::expand(graph, transpositions, game, current_turn, node, *action);
+                path.push(child);
+                return child;
             } else {
                 // All actions have been tried, recursively search the best candidate
-                let (action, best) =
-                    self.best_child(graph, node, actions, SelectionMode::Exploration);
+                let (action, best) = self.best_child(
+                    graph,
+                    node,
+                    actions.into_iter().collect(),
+                    SelectionMode::Exploration,
+                );
                 game.execute_action(current_turn, action);
                 node = best;
+                path.push(node);
             }
         }
         node
@@ -262,6 +453,10 @@ impl<TScoreAlgorithm: ChildScoreAlgorithm> MonteCarloAlgorithm<TScoreAlgorithm>
     /// provided input node. Mutates the provided [GameState] to apply the
     /// provided game action.
     ///
+    /// If the resulting game state has already been added to the tree via a
+    /// different sequence of actions, a new edge to the existing node is
+    /// added instead, so that search statistics are shared between them.
+    ///
     /// Pseudocode:
     /// ```text
     /// 𝐟𝐮𝐧𝐜𝐭𝐢𝐨𝐧 EXPAND(v)
@@ -273,13 +468,25 @@ impl<TScoreAlgorithm: ChildScoreAlgorithm> MonteCarloAlgorithm<TScoreAlgorithm>
     /// ```
     fn expand<TState: GameStateNode>(
         graph: &mut SearchGraph<TState>,
+        transpositions: &mut Transpositions<TState>,
         game: &mut TState,
         player: TState::PlayerName,
         source: NodeIndex,
         action: TState::Action,
     ) -> NodeIndex {
         game.execute_action(player, action);
-        let target = graph.add_node(SearchNode { player, total_reward: 0.0, visit_count: 0 });
+        let hash = game.zobrist_hash();
+        let target = match hash.and_then(|h| transpositions.get(&(h, player))) {
+            Some(&existing) => existing,
+            None => {
+                let target =
+                    graph.add_node(SearchNode { player, total_reward: 0.0, visit_count: 0 });
+                if let Some(hash) = hash {
+                    transpositions.insert((hash, player), target);
+                }
+                target
+            }
+        };
         graph.add_edge(source, target, SearchEdge { action });
         target
     }
@@ -333,8 +540,8 @@ impl<TScoreAlgorithm: ChildScoreAlgorithm> MonteCarloAlgorithm<TScoreAlgorithm>
     }
 
     /// Once a playout is completed, the backpropagation step walks back up the
-    /// hierarchy of parent nodes, adding the resulting reward value to each
-    /// one.
+    /// `path` of nodes visited by the tree policy, adding the resulting reward
+    /// value to each one.
     ///
     /// Pseudocode:
     /// ```text
@@ -347,19 +554,32 @@ impl<TScoreAlgorithm: ChildScoreAlgorithm> MonteCarloAlgorithm<TScoreAlgorithm>
     fn backup<TState: GameStateNode>(
         graph: &mut SearchGraph<TState>,
         maximizing_player: TState::PlayerName,
-        mut node: NodeIndex,
+        path: &[NodeIndex],
         reward: f64,
     ) {
-        loop {
+        for &node in path.iter().rev() {
             let weight = graph.node_weight_mut(node).expect("Node not found");
             weight.visit_count += 1;
             weight.total_reward +=
                 if weight.player == maximizing_player { reward } else { -reward };
+        }
+    }
 
-            node = match graph.neighbors_directed(node, Direction::Incoming).next() {
-                Some(n) => n,
-                _ => return,
-            };
+    /// Adds (if `apply` is true) or removes a virtual loss for each node in
+    /// `path`, in the form of a visit with no reward. This discourages other
+    /// threads sharing the tree from exploring the same path concurrently.
+    fn apply_virtual_loss<TState: GameStateNode>(
+        graph: &mut SearchGraph<TState>,
+        path: &[NodeIndex],
+        apply: bool,
+    ) {
+        for &node in path {
+            let weight = graph.node_weight_mut(node).expect("Node not found");
+            if apply {
+                weight.visit_count += 1;
+            } else {
+                weight.visit_count -= 1;
+            }
         }
     }
 }
@@ -0,0 +1,82 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use rand::prelude::IteratorRandom;
+use rand::{Rng, RngCore};
+
+use crate::core::game_state_node::GameStateNode;
+
+/// Trait for selecting actions during the playout (or 'rollout') phase of a
+/// Monte Carlo search, used by [PlayoutEvaluator] to play the game out to
+/// completion.
+///
+/// Playouts are run many thousands of times per search, so implementations
+/// should be cheap to compute. Better-informed playouts produce much more
+/// accurate reward estimates than purely random play.
+///
+/// [PlayoutEvaluator]: crate::monte_carlo::monte_carlo_search::PlayoutEvaluator
+pub trait RolloutPolicy<TNode: GameStateNode>: Send + Sync {
+    /// Selects an action for `player` to take in the given in-progress game
+    /// state.
+    fn select_action(
+        &self,
+        node: &TNode,
+        player: TNode::PlayerName,
+        rng: &mut dyn RngCore,
+    ) -> TNode::Action;
+}
+
+/// Rollout policy which picks uniformly at random from among all legal
+/// actions.
+pub struct RandomRollout;
+
+impl<TNode: GameStateNode> RolloutPolicy<TNode> for RandomRollout {
+    fn select_action(
+        &self,
+        node: &TNode,
+        player: TNode::PlayerName,
+        rng: &mut dyn RngCore,
+    ) -> TNode::Action {
+        node.legal_actions(player).choose(rng).expect("No actions found")
+    }
+}
+
+/// Rollout policy which follows the wrapped `policy` most of the time, but
+/// picks a uniformly random legal action with probability `epsilon`.
+///
+/// Adding some randomness to a deterministic heuristic policy prevents every
+/// playout from a given state from producing an identical result.
+pub struct EpsilonGreedy<TPolicy> {
+    pub epsilon: f64,
+    pub policy: TPolicy,
+}
+
+impl<TNode, TPolicy> RolloutPolicy<TNode> for EpsilonGreedy<TPolicy>
+where
+    TNode: GameStateNode,
+    TPolicy: RolloutPolicy<TNode>,
+{
+    fn select_action(
+        &self,
+        node: &TNode,
+        player: TNode::PlayerName,
+        rng: &mut dyn RngCore,
+    ) -> TNode::Action {
+        if rng.gen_bool(self.epsilon) {
+            RandomRollout.select_action(node, player, rng)
+        } else {
+            self.policy.select_action(node, player, rng)
+        }
+    }
+}
@@ -16,7 +16,8 @@ use std::marker::PhantomData;
 
 use crate::core::agent::AgentData;
 use crate::core::win_loss_evaluator::WinLossEvaluator;
-use crate::monte_carlo::monte_carlo_search::{MonteCarloAlgorithm, RandomPlayoutEvaluator};
+use crate::monte_carlo::monte_carlo_search::{MonteCarloAlgorithm, Parallelism, PlayoutEvaluator};
+use crate::monte_carlo::rollout_policy::RandomRollout;
 use crate::monte_carlo::uct1::Uct1;
 use crate::nim::nim_game::{NimPerfectEvaluator, NimState};
 use crate::tree_search::alpha_beta::AlphaBetaAlgorithm;
@@ -35,10 +36,18 @@ pub const NIM_ALPHA_BETA_AGENT: AgentData<AlphaBetaAlgorithm, WinLossEvaluator,
 
 pub const NIM_UCT1_AGENT: AgentData<
     MonteCarloAlgorithm<Uct1>,
-    RandomPlayoutEvaluator<NimState, WinLossEvaluator>,
+    PlayoutEvaluator<NimState, WinLossEvaluator, RandomRollout>,
     NimState,
 > = AgentData::omniscient(
     "UCT1",
-    MonteCarloAlgorithm { child_score_algorithm: Uct1 {}, max_iterations: None },
-    RandomPlayoutEvaluator { evaluator: WinLossEvaluator, phantom_data: PhantomData },
+    MonteCarloAlgorithm {
+        child_score_algorithm: Uct1 {},
+        max_iterations: None,
+        parallelism: Parallelism::Root,
+    },
+    PlayoutEvaluator {
+        evaluator: WinLossEvaluator,
+        policy: RandomRollout,
+        phantom_data: PhantomData,
+    },
 );
@@ -21,6 +21,7 @@ use enumset::EnumSetType;
 use crate::core::agent::{Agent, AgentConfig};
 use crate::core::game_state_node::{GameStateNode, GameStatus};
 use crate::core::state_evaluator::StateEvaluator;
+use crate::core::zobrist;
 
 /// Asserts that a given `agent` picks an optimal game action for the provided
 /// game state.
@@ -171,4 +172,11 @@ impl GameStateNode for NimState {
             NimPlayer::Two => NimPlayer::One,
         };
     }
+
+    fn zobrist_hash(&self) -> Option<u64> {
+        let piles = all_piles().into_iter().enumerate().fold(0, |hash, (i, pile)| {
+            hash ^ zobrist::key(((i as u64) << 32) | u64::from(self.piles[&pile]))
+        });
+        Some(piles ^ zobrist::key_for(&self.turn))
+    }
 }
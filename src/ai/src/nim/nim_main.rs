@@ -21,6 +21,8 @@ use ai::nim::nim_agents::{
 };
 use ai::nim::nim_game::{nim_sum, NimAction, NimPile, NimPlayer, NimState};
 use clap::{Parser, ValueEnum};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 #[derive(Parser)]
 #[clap()]
@@ -33,6 +35,10 @@ pub struct Args {
     pub stack_size: u32,
     #[arg(long, default_value_t = 5)]
     pub move_time: u64,
+    /// Seed for the agents' random number generators. A random seed is chosen
+    /// if not provided.
+    #[arg(long)]
+    pub seed: Option<u64>,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
@@ -47,8 +53,16 @@ pub enum NimAgentName {
 pub fn main() {
     let args = Args::parse();
     println!("Welcome to the Game of Nim");
+    let seed = args.seed.unwrap_or_else(rand::random);
+    println!("Using seed {seed}");
     let nim = NimState::new(args.stack_size);
-    run_game_loop(nim, args.move_time, get_agent(args.player_one), get_agent(args.player_two))
+    run_game_loop(
+        nim,
+        args.move_time,
+        &mut StdRng::seed_from_u64(seed),
+        get_agent(args.player_one),
+        get_agent(args.player_two),
+    )
 }
 
 fn get_agent(name: NimAgentName) -> Box<dyn Agent<NimState>> {
@@ -64,13 +78,15 @@ fn get_agent(name: NimAgentName) -> Box<dyn Agent<NimState>> {
 fn run_game_loop(
     mut nim: NimState,
     move_time: u64,
+    rng: &mut StdRng,
     player_one: Box<dyn Agent<NimState>>,
     player_two: Box<dyn Agent<NimState>>,
 ) {
     loop {
         print_optimal_action(&nim, player_one.name());
         println!("{}", nim);
-        let p1_action = player_one.pick_action(AgentConfig::with_deadline(move_time), &nim);
+        let config = AgentConfig { seed: Some(rng.gen()), ..AgentConfig::with_deadline(move_time) };
+        let p1_action = player_one.pick_action(config, &nim);
         println!("<<{}>> takes {} from {}", player_one.name(), p1_action.amount, p1_action.pile);
         nim.execute_action(NimPlayer::One, p1_action);
         check_game_over(&nim);
@@ -78,7 +94,8 @@ fn run_game_loop(
         print_optimal_action(&nim, player_two.name());
         println!("{}", nim);
 
-        let p2_action = player_two.pick_action(AgentConfig::with_deadline(move_time), &nim);
+        let config = AgentConfig { seed: Some(rng.gen()), ..AgentConfig::with_deadline(move_time) };
+        let p2_action = player_two.pick_action(config, &nim);
         println!("<<{}>> takes {} from {}", player_two.name(), p2_action.amount, p2_action.pile);
         nim.execute_action(NimPlayer::Two, p2_action);
         check_game_over(&nim);
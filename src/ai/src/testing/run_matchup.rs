@@ -13,15 +13,24 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
 use clap::{Parser, ValueEnum};
+use data::game_record::{GameRecord, RecordedAction};
+use data::pbn;
+use data::pbn::PbnBoard;
 use data::play_phase_data::PlayPhaseData;
 use data::primitive::primitives::PlayerName;
+use data::round_data::RoundData;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use rules::rounds::new_round;
 
 use crate::core::agent::AgentConfig;
 use crate::core::game_state_node::{GameStateNode, GameStatus};
+use crate::core::search_rng;
 use crate::game::agents;
 use crate::game::agents::AgentName;
 
@@ -51,52 +60,117 @@ pub struct MatchupArgs {
     /// Whether to crash the program if a search timeout is exceeded.
     #[arg(long, default_value_t = false)]
     pub panic_on_search_timeout: bool,
+    /// Number of threads for each agent to use for parallel searches
+    #[arg(long, default_value_t = 1)]
+    pub threads: usize,
+    /// Seed for the first match. Each subsequent match uses the next seed. A
+    /// random seed is chosen if not provided.
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Directory in which to write a game record for each match
+    #[arg(long)]
+    pub record_dir: Option<PathBuf>,
+    /// PBN file to read deals from instead of dealing randomly. Matches cycle
+    /// through the boards in the file.
+    #[arg(long)]
+    pub pbn: Option<PathBuf>,
+}
+
+/// Options for running a single match via [run_match].
+#[derive(Clone, Copy)]
+pub struct MatchConfig {
+    /// Maximum time in milliseconds for each agent to use for moves.
+    pub move_time_ms: u64,
+    pub verbosity: Verbosity,
+    pub panic_on_search_timeout: bool,
+    pub threads: usize,
+    /// Seed from which each agent's per-move seed is derived.
+    pub seed: u64,
 }
 
 pub fn run_with_args(args: &MatchupArgs) {
     let user = agents::get_agent(args.user);
     let opponent = agents::get_agent(args.opponent);
 
+    let boards = args.pbn.as_ref().map(|path| {
+        let text = fs::read_to_string(path).expect("Error reading PBN file");
+        pbn::parse_boards(&text).expect("Error parsing PBN file")
+    });
+    let first_seed = args.seed.unwrap_or_else(rand::random);
     for i in 1..=args.matches {
+        let seed = first_seed.wrapping_add(i - 1);
         if args.verbosity >= Verbosity::Matches {
-            println!(">>> Running match {} between {} and {}", i, user.name(), opponent.name());
+            println!(
+                ">>> Running match {} between {} and {} with seed {}",
+                i,
+                user.name(),
+                opponent.name(),
+                seed
+            );
+        }
+        let mut game = if let Some(boards) = &boards {
+            let board = &boards[(i - 1) as usize % boards.len()];
+            new_round::play_phase_from_deal(board.hands.clone(), board.trump, 3)
+        } else {
+            new_round::create_play_phase(&mut StdRng::seed_from_u64(seed), 3)
+        };
+        if args.verbosity >= Verbosity::Matches {
+            print!(
+                "{}",
+                pbn::format_board(&PbnBoard { hands: game.hands.clone(), trump: game.trump })
+            );
+        }
+        let result = run_match(args.user, args.opponent, &mut game, MatchConfig {
+            move_time_ms: args.move_time_ms,
+            verbosity: args.verbosity,
+            panic_on_search_timeout: args.panic_on_search_timeout,
+            threads: args.threads,
+            seed,
+        });
+        if let Some(directory) = &args.record_dir {
+            write_record(directory, seed, &result.record);
         }
-        let mut game = new_round::create_play_phase(&mut rand::thread_rng(), 3);
-        run_match(
-            args.user,
-            args.opponent,
-            &mut game,
-            args.move_time_ms,
-            args.verbosity,
-            args.panic_on_search_timeout,
-        );
     }
 }
 
+/// Result of running a single match.
+pub struct MatchResult {
+    /// Score for each agent in the match.
+    pub scores: HashMap<AgentName, i32>,
+    /// Record of every action taken in the match.
+    pub record: GameRecord,
+}
+
 pub fn run_match(
     user_agent: AgentName,
     opponent_agent: AgentName,
     game: &mut PlayPhaseData,
-    move_time_ms: u64,
-    verbosity: Verbosity,
-    panic_on_search_timeout: bool,
-) -> HashMap<AgentName, i32> {
+    config: MatchConfig,
+) -> MatchResult {
+    let verbosity = config.verbosity;
     let user = agents::get_agent(user_agent);
     let opponent = agents::get_agent(opponent_agent);
     if verbosity > Verbosity::None {
         println!("Starting game");
     }
 
+    // Agent seeds are drawn from a separate stream from the one used to deal the
+    // game, so they can't coincide.
+    let mut rng = StdRng::seed_from_u64(search_rng::derive_seed(config.seed));
+    let mut record = GameRecord::new(&RoundData::PlayPhase(game.clone()));
     loop {
         match game.status() {
             GameStatus::InProgress { current_turn } => {
                 let agent = if current_turn == PlayerName::User { &user } else { &opponent };
-                let config = AgentConfig {
-                    panic_on_search_timeout,
-                    deadline: Instant::now() + Duration::from_millis(move_time_ms),
+                let agent_config = AgentConfig {
+                    panic_on_search_timeout: config.panic_on_search_timeout,
+                    deadline: Instant::now() + Duration::from_millis(config.move_time_ms),
+                    threads: config.threads,
+                    seed: Some(rng.gen()),
                 };
-                let action = agent.pick_action(config, game);
+                let action = agent.pick_action(agent_config, game);
                 game.execute_action(current_turn, action);
+                record.actions.push(RecordedAction::Play(current_turn, action));
                 clear_action_line(verbosity);
                 if verbosity > Verbosity::None {
                     println!("{} performs action {:?}", agent.name(), action);
@@ -107,7 +181,7 @@ pub fn run_match(
                     clear_action_line(verbosity);
                     println!("Match ended with scores {:?}", scores);
                 }
-                return scores
+                let scores = scores
                     .iter()
                     .map(|(&player, &score)| {
                         (
@@ -116,11 +190,19 @@ pub fn run_match(
                         )
                     })
                     .collect();
+                return MatchResult { scores, record };
             }
         }
     }
 }
 
+fn write_record(directory: &Path, seed: u64, record: &GameRecord) {
+    fs::create_dir_all(directory).expect("Error creating record directory");
+    let json = serde_json::to_string_pretty(record).expect("Error serializing game record");
+    fs::write(directory.join(format!("match-{seed}.json")), json)
+        .expect("Error writing game record");
+}
+
 fn clear_action_line(verbosity: Verbosity) {
     if verbosity == Verbosity::Matches {
         print!("\x1B[1F"); // Moves cursor to beginning of previous line, 1 line up
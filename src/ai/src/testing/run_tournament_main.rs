@@ -16,9 +16,11 @@ use std::collections::HashMap;
 
 use ai::game::agents::AgentName;
 use ai::testing::run_matchup;
-use ai::testing::run_matchup::Verbosity;
+use ai::testing::run_matchup::{MatchConfig, Verbosity};
 use clap::Parser;
 use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use rules::rounds::new_round;
 
 #[derive(Parser)]
@@ -29,16 +31,25 @@ pub struct TournamentArgs {
     /// Maximum time in seconds for each agent to use for moves.
     #[arg(long, default_value_t = 1)]
     pub move_time: u64,
+    /// Number of threads for each agent to use for parallel searches
+    #[arg(long, default_value_t = 1)]
+    pub threads: usize,
+    /// Seed used to generate the seed for each deal. A random seed is chosen if
+    /// not provided.
+    #[arg(long)]
+    pub seed: Option<u64>,
 }
 
 pub fn main() {
     programs::linkme();
     let args = TournamentArgs::parse();
-    println!("Running Tournament: {:?}", args.players);
+    let seed = args.seed.unwrap_or_else(rand::random);
+    println!("Running Tournament: {:?} with seed {}", args.players, seed);
+    let mut rng = StdRng::seed_from_u64(seed);
     let mut scores = HashMap::new();
     for combination in args.players.iter().combinations(2) {
         println!("Running 10-game match between {:?} and {:?}", combination[0], combination[1]);
-        run_tournament_match(&args, &mut scores, *combination[0], *combination[1]);
+        run_tournament_match(&args, &mut rng, &mut scores, *combination[0], *combination[1]);
         println!("\nUpdated Scores:");
         print_scores(&scores);
     }
@@ -49,22 +60,26 @@ pub fn main() {
 
 fn run_tournament_match(
     args: &TournamentArgs,
+    rng: &mut StdRng,
     scores: &mut HashMap<AgentName, i32>,
     p1: AgentName,
     p2: AgentName,
 ) {
     for _ in 0..5 {
         // Players play the game from both sides in order to ensure fairness
-        let mut game1 = new_round::create_play_phase(&mut rand::thread_rng(), 3);
+        let seed = rng.gen();
+        println!("Dealing game with seed {seed}");
+        let mut game1 = new_round::create_play_phase(&mut StdRng::seed_from_u64(seed), 3);
         let mut game2 = game1.clone();
-        add_winner(
-            scores,
-            run_matchup::run_match(p1, p2, &mut game1, args.move_time, Verbosity::Matches, false),
-        );
-        add_winner(
-            scores,
-            run_matchup::run_match(p2, p1, &mut game2, args.move_time, Verbosity::Matches, false),
-        );
+        let config = MatchConfig {
+            move_time_ms: args.move_time,
+            verbosity: Verbosity::Matches,
+            panic_on_search_timeout: false,
+            threads: args.threads,
+            seed,
+        };
+        add_winner(scores, run_matchup::run_match(p1, p2, &mut game1, config).scores);
+        add_winner(scores, run_matchup::run_match(p2, p1, &mut game2, config).scores);
     }
 }
 
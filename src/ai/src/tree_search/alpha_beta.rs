@@ -12,8 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::cmp;
+use std::collections::HashMap;
 use std::time::Instant;
+use std::{cmp, mem};
 
 use tracing::debug;
 
@@ -28,6 +29,21 @@ use crate::tree_search::scored_action::ScoredAction;
 /// This is a 'fail soft' implementation per wikipedia. I have not been able to
 /// detect any performance or gameplay difference with the 'fail hard' version.
 ///
+/// The search is run via iterative deepening: the tree is searched to depth 1,
+/// then depth 2, and so on up to `search_depth`. If the deadline is exceeded,
+/// the in-progress iteration is discarded and the best action from the last
+/// completed iteration is returned. Results from earlier iterations are used to
+/// order moves in later ones, which more than pays for the repeated work:
+///
+/// - **Principal variation:** The sequence of best actions found by the
+///   previous iteration is searched first.
+/// - **Transposition table:** If the game supports
+///   [GameStateNode::zobrist_hash], bounds and best actions for each position
+///   are stored, so that positions reached via multiple action orderings are
+///   only searched once and previously-best actions are searched first.
+/// - **Killer moves:** Actions which recently caused a cutoff at the same
+///   search ply are searched next.
+///
 /// See <https://en.wikipedia.org/wiki/Alpha-beta_pruning>
 pub struct AlphaBetaAlgorithm {
     pub search_depth: u32,
@@ -46,83 +62,251 @@ impl SelectionAlgorithm for AlphaBetaAlgorithm {
         E: StateEvaluator<N>,
     {
         assert!(matches!(node.status(), GameStatus::InProgress { .. }));
-        run_internal(config, node, evaluator, self.search_depth, player, i32::MIN, i32::MAX, true)
-            .action()
+        let mut search = Search {
+            config,
+            evaluator,
+            player,
+            table: HashMap::new(),
+            killers: vec![],
+            principal_variation: vec![],
+            pv_table: vec![],
+            aborted: false,
+            depth_limited: false,
+        };
+
+        let mut best = None;
+        for depth in 1..=self.search_depth {
+            search.depth_limited = false;
+            let result = search.run(node, depth, 0, i32::MIN, i32::MAX, true);
+            if search.aborted {
+                debug!("Deadline exceeded at depth {:?}", depth);
+                // If no iteration completed, a partial result is better than nothing
+                best = best.or(Some(result.action()));
+                break;
+            }
+
+            debug!("Completed depth {:?} with score {:?}", depth, result.score());
+            best = Some(result.action());
+            search.principal_variation = search.pv_table[0].clone();
+            if !search.depth_limited {
+                // Every line reached the end of the game, so searching deeper would
+                // produce the same result
+                break;
+            }
+        }
+        best.expect("No action found")
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn run_internal<N, E>(
-    config: AgentConfig,
-    node: &N,
-    evaluator: &E,
+/// Relationship between a score stored in the transposition table and the
+/// true minimax value of its position.
+#[derive(Clone, Copy)]
+enum Bound {
+    Exact,
+    /// The true value is at least the stored score (a beta cutoff occurred)
+    Lower,
+    /// The true value is at most the stored score (an alpha cutoff occurred)
+    Upper,
+}
+
+/// Transposition table entry for a previously-searched position.
+#[derive(Clone, Copy)]
+struct TableEntry<A> {
+    /// Remaining search depth below this position when it was searched.
     depth: u32,
+    score: i32,
+    bound: Bound,
+    /// Best action found for this position, if any.
+    action: Option<A>,
+    /// True if any line below this position was cut off by the depth limit,
+    /// i.e. the score is not the exact value at the end of the game.
+    depth_limited: bool,
+}
+
+/// Minimum remaining search depth for positions to be stored in the
+/// transposition table.
+const MIN_TABLE_DEPTH: u32 = 3;
+
+/// State shared across all iterations of an iterative deepening search.
+struct Search<'a, N: GameStateNode, E> {
+    config: AgentConfig,
+    evaluator: &'a E,
     player: N::PlayerName,
-    mut alpha: i32,
-    mut beta: i32,
-    top_level: bool,
-) -> ScoredAction<N::Action>
+    /// Transposition table, keyed by [GameStateNode::zobrist_hash].
+    table: HashMap<u64, TableEntry<N::Action>>,
+    /// Up to two actions which most recently caused a cutoff at each ply.
+    killers: Vec<[Option<N::Action>; 2]>,
+    /// Best line of play found by the last completed iteration.
+    principal_variation: Vec<N::Action>,
+    /// Best line of play found from each ply in the current iteration.
+    pv_table: Vec<Vec<N::Action>>,
+    /// True if the deadline was exceeded during the current iteration.
+    aborted: bool,
+    /// True if any line in the current iteration was cut off by the depth
+    /// limit rather than reaching the end of the game.
+    depth_limited: bool,
+}
+
+impl<'a, N, E> Search<'a, N, E>
 where
     N: GameStateNode,
     E: StateEvaluator<N>,
 {
-    match node.status() {
-        _ if depth == 0 => ScoredAction::new(evaluator.evaluate(node, player)),
-        GameStatus::Completed { .. } => ScoredAction::new(evaluator.evaluate(node, player)),
-        GameStatus::InProgress { current_turn } if current_turn == player => {
-            let mut result = ScoredAction::new(i32::MIN);
-            for action in node.legal_actions(current_turn) {
-                if deadline_exceeded(config, depth) {
-                    return result.with_fallback_action(action);
-                }
-                let mut child = node.make_copy();
-                child.execute_action(current_turn, action);
-                let score =
-                    run_internal(config, &child, evaluator, depth - 1, player, alpha, beta, false)
-                        .score();
-                if top_level {
-                    debug!("Score {:?} for action {:?}", score, action);
-                }
-                alpha = cmp::max(alpha, score);
-                result.insert_max(action, score);
-                if score >= beta {
-                    break; // Beta cutoff
-                }
+    /// Searches `node` to the given remaining `depth`. `ply` is the number of
+    /// actions taken from the root, and `on_pv` indicates whether every action
+    /// taken so far follows the previous iteration's principal variation.
+    fn run(
+        &mut self,
+        node: &N,
+        depth: u32,
+        ply: usize,
+        mut alpha: i32,
+        mut beta: i32,
+        on_pv: bool,
+    ) -> ScoredAction<N::Action> {
+        if self.pv_table.len() <= ply {
+            self.pv_table.resize(ply + 1, vec![]);
+            self.killers.resize(ply + 1, [None, None]);
+        }
+        self.pv_table[ply].clear();
+
+        let current_turn = match node.status() {
+            GameStatus::Completed { .. } => {
+                return ScoredAction::new(self.evaluator.evaluate(node, self.player))
+            }
+            _ if depth == 0 => {
+                self.depth_limited = true;
+                return ScoredAction::new(self.evaluator.evaluate(node, self.player));
+            }
+            GameStatus::InProgress { current_turn } => current_turn,
+        };
+
+        // The top level always needs to be searched in order to produce an action,
+        // and positions close to the leaves are cheaper to search than to hash.
+        let hash = if depth < MIN_TABLE_DEPTH { None } else { node.zobrist_hash() };
+        let entry = hash.and_then(|h| self.table.get(&h)).copied();
+        if let Some(entry) = entry.filter(|e| ply > 0 && e.depth >= depth) {
+            let cutoff = match entry.bound {
+                Bound::Exact => true,
+                Bound::Lower => entry.score >= beta,
+                Bound::Upper => entry.score <= alpha,
+            };
+            if cutoff {
+                self.depth_limited |= entry.depth_limited;
+                return ScoredAction::new(entry.score);
             }
-            result
         }
-        GameStatus::InProgress { current_turn } => {
-            let mut result = ScoredAction::new(i32::MAX);
-            for action in node.legal_actions(current_turn) {
-                if deadline_exceeded(config, depth) {
-                    return result.with_fallback_action(action);
-                }
-                let mut child = node.make_copy();
-                child.execute_action(current_turn, action);
-                let score =
-                    run_internal(config, &child, evaluator, depth - 1, player, alpha, beta, false)
-                        .score();
-                if top_level {
-                    debug!("Score {:?} for action {:?}", score, action);
-                }
+
+        // Track whether this subtree alone is depth limited, so it can be
+        // recorded in the transposition table
+        let outer_depth_limited = mem::replace(&mut self.depth_limited, false);
+
+        let pv_action = self.principal_variation.get(ply).copied().filter(|_| on_pv);
+        let actions = self.order_actions(node.legal_actions(current_turn).collect(), &[
+            pv_action,
+            entry.and_then(|e| e.action),
+            self.killers[ply][0],
+            self.killers[ply][1],
+        ]);
+
+        let maximizing = current_turn == self.player;
+        let (original_alpha, original_beta) = (alpha, beta);
+        let mut result = ScoredAction::new(if maximizing { i32::MIN } else { i32::MAX });
+        for action in actions {
+            if self.deadline_exceeded(depth) {
+                return result.with_fallback_action(action);
+            }
+            let mut child = node.make_copy();
+            child.execute_action(current_turn, action);
+            let child_on_pv = on_pv && pv_action == Some(action);
+            let score = self.run(&child, depth - 1, ply + 1, alpha, beta, child_on_pv).score();
+            if self.aborted {
+                return result.with_fallback_action(action);
+            }
+            if ply == 0 {
+                debug!("Score {:?} for action {:?}", score, action);
+            }
+
+            let improved = if maximizing {
+                alpha = cmp::max(alpha, score);
+                !result.has_action() || score > result.score()
+            } else {
                 beta = cmp::min(beta, score);
-                result.insert_min(action, score);
-                if score <= alpha {
-                    break; // Alpha cutoff
+                !result.has_action() || score < result.score()
+            };
+            if improved {
+                if maximizing {
+                    result.insert_max(action, score);
+                } else {
+                    result.insert_min(action, score);
                 }
+                let (current, rest) = self.pv_table.split_at_mut(ply + 1);
+                current[ply].clear();
+                current[ply].push(action);
+                if let Some(line) = rest.first() {
+                    current[ply].extend_from_slice(line);
+                }
+            }
+
+            if (maximizing && score >= beta) || (!maximizing && score <= alpha) {
+                // Cutoff
+                self.add_killer(ply, action);
+                break;
             }
-            assert!(result.has_action());
-            result
         }
+        assert!(result.has_action());
+        let depth_limited = self.depth_limited;
+        self.depth_limited |= outer_depth_limited;
+
+        if let Some(hash) = hash {
+            let score = result.score();
+            let bound = if score <= original_alpha {
+                Bound::Upper
+            } else if score >= original_beta {
+                Bound::Lower
+            } else {
+                Bound::Exact
+            };
+            let action = Some(result.action());
+            self.table.insert(hash, TableEntry { depth, score, bound, action, depth_limited });
+        }
+
+        result
     }
-}
 
-/// Check whether `deadline` has been exceeded. Only checks deadlines for higher
-/// parts of the tree to avoid excessive calls to Instant::now().
-fn deadline_exceeded(config: AgentConfig, depth: u32) -> bool {
-    let exceeded = depth > 1 && config.deadline < Instant::now();
-    if exceeded && config.panic_on_search_timeout {
-        panic!("Search deadline exceeded!");
+    /// Moves each of the `preferred` actions which is present in `actions` to
+    /// the front of the list, in order.
+    fn order_actions(
+        &self,
+        mut actions: Vec<N::Action>,
+        preferred: &[Option<N::Action>],
+    ) -> Vec<N::Action> {
+        let mut next = 0;
+        for action in preferred.iter().flatten() {
+            if let Some(i) = actions[next..].iter().position(|a| a == action) {
+                actions[next..=next + i].rotate_right(1);
+                next += 1;
+            }
+        }
+        actions
+    }
+
+    fn add_killer(&mut self, ply: usize, action: N::Action) {
+        let killers = &mut self.killers[ply];
+        if killers[0] != Some(action) {
+            killers[1] = killers[0];
+            killers[0] = Some(action);
+        }
+    }
+
+    /// Check whether `deadline` has been exceeded. Only checks deadlines for
+    /// higher parts of the tree to avoid excessive calls to Instant::now().
+    fn deadline_exceeded(&mut self, depth: u32) -> bool {
+        let exceeded = depth > 1 && self.config.deadline < Instant::now();
+        if exceeded && self.config.panic_on_search_timeout {
+            panic!("Search deadline exceeded!");
+        }
+        self.aborted |= exceeded;
+        exceeded
     }
-    exceeded
 }
@@ -0,0 +1,116 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::contract_phase_data::Contracts;
+use crate::primitive::numerics::Intel;
+use crate::primitive::primitives::PlayerName;
+use crate::program_name::ProgramName;
+
+/// State of a campaign: a sequence of rounds with a running [Intel] total for
+/// each player.
+///
+/// A campaign ends once it has played `max_rounds` rounds or once any player's
+/// total reaches `target`. Intel spent on programs still counts towards the
+/// total.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CampaignData {
+    /// Maximum number of rounds to play
+    pub max_rounds: usize,
+    /// Total [Intel] which ends the campaign when reached by any player
+    pub target: Intel,
+    /// Player who leads the first trick of the current round
+    pub leader: PlayerName,
+    /// Results of each completed round, in order
+    pub results: Vec<RoundResult>,
+    /// Programs owned by each player, carried forward into each new round
+    #[serde(default)]
+    pub programs: HashMap<PlayerName, Vec<ProgramName>>,
+    /// Intel each player has spent on programs
+    #[serde(default)]
+    pub spent: HashMap<PlayerName, Intel>,
+}
+
+impl CampaignData {
+    /// Creates a new campaign in which each player starts out owning the
+    /// provided `programs`.
+    pub fn new(
+        max_rounds: usize,
+        target: Intel,
+        programs: HashMap<PlayerName, Vec<ProgramName>>,
+    ) -> Self {
+        Self {
+            max_rounds,
+            target,
+            leader: PlayerName::User,
+            results: vec![],
+            programs,
+            spent: HashMap::new(),
+        }
+    }
+
+    /// Total [Intel] earned by the [PlayerName] player across all completed
+    /// rounds.
+    pub fn total(&self, player: PlayerName) -> Intel {
+        self.results.iter().map(|r| r.intel(player)).sum()
+    }
+
+    /// Intel the [PlayerName] player has earned but not yet spent on programs.
+    pub fn available(&self, player: PlayerName) -> Intel {
+        self.total(player) - self.spent.get(&player).copied().unwrap_or(Intel(0))
+    }
+
+    /// Number of times the user undid an action across all completed rounds.
+    pub fn undos(&self) -> usize {
+        self.results.iter().map(|r| r.undos).sum()
+    }
+
+    /// Returns true if the [PlayerName] player owns the [ProgramName] program.
+    pub fn owns(&self, player: PlayerName, program: ProgramName) -> bool {
+        self.programs.get(&player).is_some_and(|p| p.contains(&program))
+    }
+}
+
+/// Outcome of a single completed round within a campaign.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundResult {
+    pub contracts: Contracts,
+    /// Number of tricks won by each player
+    pub tricks: HashMap<PlayerName, usize>,
+    /// Intel earned by each player
+    pub intel: HashMap<PlayerName, Intel>,
+    /// Number of times the user undid an action during this round. A campaign
+    /// with any undos is unranked.
+    #[serde(default)]
+    pub undos: usize,
+}
+
+impl RoundResult {
+    pub fn tricks(&self, player: PlayerName) -> usize {
+        self.tricks.get(&player).copied().unwrap_or_default()
+    }
+
+    /// Returns true if the [PlayerName] player won at least as many tricks as
+    /// their contract.
+    pub fn made_contract(&self, player: PlayerName) -> bool {
+        self.tricks(player) >= self.contracts.contract_number(player)
+    }
+
+    pub fn intel(&self, player: PlayerName) -> Intel {
+        self.intel.get(&player).copied().unwrap_or(Intel(0))
+    }
+}
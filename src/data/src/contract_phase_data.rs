@@ -15,6 +15,8 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 
+use serde::{Deserialize, Serialize};
+
 use crate::delegate_data::{ContractPhaseDelegates, PlayPhaseDelegates};
 use crate::game_action::GameAction;
 use crate::play_phase_data::{Hands, PlayPhaseData, Trick};
@@ -23,7 +25,7 @@ use crate::program_data::ProgramData;
 
 pub type ContractNumber = usize;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ContractPhaseStep {
     AwaitingUserContact,
     AwaitingAgentContracts,
@@ -41,6 +43,8 @@ pub struct ContractPhaseData {
     pub hands: Hands,
     /// Current step within the contract phase
     pub step: ContractPhaseStep,
+    /// Player who will lead the first trick in the play phase
+    pub leader: PlayerName,
     /// Data about program for the players in this round
     pub programs: ProgramData<ContractPhaseDelegates>,
 }
@@ -56,7 +60,8 @@ impl ContractPhaseData {
 
         PlayPhaseData {
             current_trick: Trick::default(),
-            turn: Some(PlayerName::User),
+            turn: Some(self.leader),
+            leader: self.leader,
             completed_tricks: vec![],
             trump: self.trump,
             contracts: self.contracts,
@@ -66,7 +71,7 @@ impl ContractPhaseData {
     }
 }
 
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
 pub struct Contracts {
     user_contract: ContractNumber,
     west_contract: ContractNumber,
@@ -94,7 +99,7 @@ impl Contracts {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum ContractPhaseAction {
     SetUserContract(ContractNumber),
     AcceptUserContract,
@@ -14,6 +14,8 @@
 
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
+
 use crate::play_phase_data::{PlayPhaseData, TrickNumber};
 use crate::primitive::primitives::PlayerName;
 use crate::program_name::ProgramName;
@@ -42,13 +44,13 @@ pub trait HasPrograms {
     fn activate(&mut self, program: ProgramId);
 }
 
-#[derive(Eq, PartialEq, Clone, Copy)]
+#[derive(Eq, PartialEq, Clone, Copy, Hash, Serialize, Deserialize)]
 pub enum ProgramState {
     ActivatedForTrick(TrickNumber),
     Activated,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct ProgramId {
     pub name: ProgramName,
     pub owner: PlayerName,
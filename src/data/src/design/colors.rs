@@ -14,15 +14,12 @@
 
 use ratatui::style::Color;
 
+use crate::design::theme::{self, Palette};
 use crate::primitive::primitives::Suit;
 
+/// Color for cards of the given suit in the active theme
 pub fn suit(suit: Suit) -> Color {
-    match suit {
-        Suit::Clubs => light_blue(),
-        Suit::Diamonds => peach(),
-        Suit::Hearts => red(),
-        Suit::Spades => cyan(),
-    }
+    theme::current().suit_color(suit)
 }
 
 pub fn trump(trump_suit: Option<Suit>) -> Color {
@@ -36,74 +33,78 @@ pub fn can_activate() -> Color {
     yellow()
 }
 
+pub fn trick_winner() -> Color {
+    light_green()
+}
+
 pub fn selected() -> Color {
     dark_gray()
 }
 
 pub fn black() -> Color {
-    color("#140C1C")
+    palette().black
 }
 
 pub fn dark_red() -> Color {
-    color("#442434")
+    palette().dark_red
 }
 
 pub fn dark_blue() -> Color {
-    color("#30346D")
+    palette().dark_blue
 }
 
 pub fn dark_gray() -> Color {
-    color("#4E4A4F")
+    palette().dark_gray
 }
 
 pub fn brown() -> Color {
-    color("#854C30")
+    palette().brown
 }
 
 pub fn dark_green() -> Color {
-    color("#346524")
+    palette().dark_green
 }
 
 pub fn red() -> Color {
-    color("#D04648")
+    palette().red
 }
 
 pub fn light_gray() -> Color {
-    color("#757161")
+    palette().light_gray
 }
 
 pub fn light_blue() -> Color {
-    color("#597DCE")
+    palette().light_blue
 }
 
 pub fn orange() -> Color {
-    color("#D27D2C")
+    palette().orange
 }
 
 pub fn blue_gray() -> Color {
-    color("#8595A1")
+    palette().blue_gray
 }
 
 pub fn light_green() -> Color {
-    color("#6DAA2C")
+    palette().light_green
 }
 
 pub fn peach() -> Color {
-    color("#D2AA99")
+    palette().peach
 }
 
 pub fn cyan() -> Color {
-    color("#6DC2CA")
+    palette().cyan
 }
 
 pub fn yellow() -> Color {
-    color("#DAD45E")
+    palette().yellow
 }
 
 pub fn white() -> Color {
-    color("#DEEED6")
+    palette().white
 }
 
-fn color(s: &'static str) -> Color {
-    s.parse().expect("Invalid color literal")
+fn palette() -> &'static Palette {
+    theme::current().palette()
 }
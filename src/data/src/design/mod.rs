@@ -14,3 +14,4 @@
 
 pub mod colors;
 pub mod symbols;
+pub mod theme;
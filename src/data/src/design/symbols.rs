@@ -14,9 +14,24 @@
 
 use ratatui::prelude::*;
 
-use crate::design::colors;
-use crate::primitive::primitives::Suit;
+use crate::design::{colors, theme};
+use crate::primitive::primitives::{Card, Suit};
 
 pub fn suit(suit: Suit) -> Span<'static> {
-    suit.to_string().fg(colors::suit(suit))
+    suit_glyph(suit).fg(colors::suit(suit))
+}
+
+/// Symbol for the given suit in the active theme
+pub fn suit_glyph(suit: Suit) -> &'static str {
+    theme::current().suit_glyph(suit)
+}
+
+/// Name of the given trump suit, or "NT" for no trump
+pub fn trump(trump: Option<Suit>) -> &'static str {
+    trump.map_or("NT", suit_glyph)
+}
+
+/// Rank and suit symbol for the given card, e.g. "10♥"
+pub fn card(card: Card) -> String {
+    format!("{}{}", card.rank(), suit_glyph(card.suit()))
 }
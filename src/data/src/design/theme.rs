@@ -0,0 +1,214 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{self, Display, Formatter};
+use std::sync::RwLock;
+
+use enum_iterator::Sequence;
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+
+use crate::primitive::primitives::Suit;
+
+/// Color theme used to render the game
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Sequence, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ThemeName {
+    #[default]
+    Default,
+    HighContrast,
+    Monochrome,
+    /// Uses only the 16 standard terminal colors, for terminals without
+    /// truecolor support
+    #[serde(rename = "16-color")]
+    Ansi16,
+}
+
+impl Display for ThemeName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            ThemeName::Default => "Default",
+            ThemeName::HighContrast => "High Contrast",
+            ThemeName::Monochrome => "Monochrome",
+            ThemeName::Ansi16 => "16 Color",
+        })
+    }
+}
+
+/// The active theme, read by [crate::design::colors] and
+/// [crate::design::symbols] while rendering.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Theme {
+    pub name: ThemeName,
+    /// Colors suits with a colorblind-safe palette and draws hearts & diamonds
+    /// as outlined glyphs, so no two suits differ only by color.
+    pub colorblind: bool,
+}
+
+static ACTIVE: RwLock<Theme> = RwLock::new(Theme { name: ThemeName::Default, colorblind: false });
+
+/// Changes the theme used for all subsequent rendering.
+pub fn set(theme: Theme) {
+    *ACTIVE.write().expect("Theme lock poisoned") = theme;
+}
+
+pub fn current() -> Theme {
+    *ACTIVE.read().expect("Theme lock poisoned")
+}
+
+/// Concrete colors for each named color in [crate::design::colors]
+pub struct Palette {
+    pub black: Color,
+    pub dark_red: Color,
+    pub dark_blue: Color,
+    pub dark_gray: Color,
+    pub brown: Color,
+    pub dark_green: Color,
+    pub red: Color,
+    pub light_gray: Color,
+    pub light_blue: Color,
+    pub orange: Color,
+    pub blue_gray: Color,
+    pub light_green: Color,
+    pub peach: Color,
+    pub cyan: Color,
+    pub yellow: Color,
+    pub white: Color,
+}
+
+impl Theme {
+    pub fn palette(&self) -> &'static Palette {
+        match self.name {
+            ThemeName::Default => &DEFAULT,
+            ThemeName::HighContrast => &HIGH_CONTRAST,
+            ThemeName::Monochrome => &MONOCHROME,
+            ThemeName::Ansi16 => &ANSI_16,
+        }
+    }
+
+    pub fn suit_color(&self, suit: Suit) -> Color {
+        let palette = self.palette();
+        match (self.name, self.colorblind) {
+            (ThemeName::Monochrome, _) => palette.white,
+            (ThemeName::Ansi16, true) => match suit {
+                Suit::Clubs => Color::LightGreen,
+                Suit::Diamonds => Color::LightYellow,
+                Suit::Hearts => Color::LightMagenta,
+                Suit::Spades => Color::LightBlue,
+            },
+            // Okabe & Ito's colorblind-safe palette
+            (_, true) => match suit {
+                Suit::Clubs => Color::Rgb(0x00, 0x9E, 0x73),
+                Suit::Diamonds => Color::Rgb(0xE6, 0x9F, 0x00),
+                Suit::Hearts => Color::Rgb(0xCC, 0x79, 0xA7),
+                Suit::Spades => Color::Rgb(0x56, 0xB4, 0xE9),
+            },
+            (_, false) => match suit {
+                Suit::Clubs => palette.light_blue,
+                Suit::Diamonds => palette.peach,
+                Suit::Hearts => palette.red,
+                Suit::Spades => palette.cyan,
+            },
+        }
+    }
+
+    pub fn suit_glyph(&self, suit: Suit) -> &'static str {
+        match (suit, self.colorblind) {
+            (Suit::Diamonds, true) => "♢",
+            (Suit::Hearts, true) => "♡",
+            (Suit::Clubs, _) => "♣",
+            (Suit::Diamonds, _) => "♦",
+            (Suit::Hearts, _) => "♥",
+            (Suit::Spades, _) => "♠",
+        }
+    }
+}
+
+const DEFAULT: Palette = Palette {
+    black: Color::Rgb(0x14, 0x0C, 0x1C),
+    dark_red: Color::Rgb(0x44, 0x24, 0x34),
+    dark_blue: Color::Rgb(0x30, 0x34, 0x6D),
+    dark_gray: Color::Rgb(0x4E, 0x4A, 0x4F),
+    brown: Color::Rgb(0x85, 0x4C, 0x30),
+    dark_green: Color::Rgb(0x34, 0x65, 0x24),
+    red: Color::Rgb(0xD0, 0x46, 0x48),
+    light_gray: Color::Rgb(0x75, 0x71, 0x61),
+    light_blue: Color::Rgb(0x59, 0x7D, 0xCE),
+    orange: Color::Rgb(0xD2, 0x7D, 0x2C),
+    blue_gray: Color::Rgb(0x85, 0x95, 0xA1),
+    light_green: Color::Rgb(0x6D, 0xAA, 0x2C),
+    peach: Color::Rgb(0xD2, 0xAA, 0x99),
+    cyan: Color::Rgb(0x6D, 0xC2, 0xCA),
+    yellow: Color::Rgb(0xDA, 0xD4, 0x5E),
+    white: Color::Rgb(0xDE, 0xEE, 0xD6),
+};
+
+const HIGH_CONTRAST: Palette = Palette {
+    black: Color::Rgb(0x00, 0x00, 0x00),
+    dark_red: Color::Rgb(0x80, 0x00, 0x20),
+    dark_blue: Color::Rgb(0x00, 0x00, 0x90),
+    dark_gray: Color::Rgb(0x40, 0x40, 0x40),
+    brown: Color::Rgb(0xA0, 0x50, 0x00),
+    dark_green: Color::Rgb(0x00, 0x80, 0x00),
+    red: Color::Rgb(0xFF, 0x30, 0x30),
+    light_gray: Color::Rgb(0xC0, 0xC0, 0xC0),
+    light_blue: Color::Rgb(0x40, 0x90, 0xFF),
+    orange: Color::Rgb(0xFF, 0x90, 0x00),
+    blue_gray: Color::Rgb(0xA0, 0xC0, 0xE0),
+    light_green: Color::Rgb(0x40, 0xFF, 0x40),
+    peach: Color::Rgb(0xFF, 0xC0, 0x90),
+    cyan: Color::Rgb(0x00, 0xFF, 0xFF),
+    yellow: Color::Rgb(0xFF, 0xFF, 0x00),
+    white: Color::Rgb(0xFF, 0xFF, 0xFF),
+};
+
+/// Dim text is gray and highlights are bright white, so states which other
+/// themes show with color remain distinguishable.
+const MONOCHROME: Palette = Palette {
+    black: Color::Black,
+    dark_red: Color::DarkGray,
+    dark_blue: Color::DarkGray,
+    dark_gray: Color::DarkGray,
+    brown: Color::DarkGray,
+    dark_green: Color::DarkGray,
+    red: Color::White,
+    light_gray: Color::DarkGray,
+    light_blue: Color::White,
+    orange: Color::White,
+    blue_gray: Color::Gray,
+    light_green: Color::White,
+    peach: Color::White,
+    cyan: Color::White,
+    yellow: Color::White,
+    white: Color::Gray,
+};
+
+const ANSI_16: Palette = Palette {
+    black: Color::Black,
+    dark_red: Color::Red,
+    dark_blue: Color::Blue,
+    dark_gray: Color::DarkGray,
+    brown: Color::Yellow,
+    dark_green: Color::Green,
+    red: Color::LightRed,
+    light_gray: Color::Gray,
+    light_blue: Color::LightBlue,
+    orange: Color::Yellow,
+    blue_gray: Color::Gray,
+    light_green: Color::LightGreen,
+    peach: Color::LightMagenta,
+    cyan: Color::LightCyan,
+    yellow: Color::LightYellow,
+    white: Color::White,
+};
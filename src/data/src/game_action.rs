@@ -14,6 +14,7 @@
 
 use crate::contract_phase_data::ContractPhaseAction;
 use crate::play_phase_data::PlayPhaseAction;
+use crate::program_name::ProgramName;
 use crate::widget_id::WidgetId;
 
 #[derive(Debug, Clone, Copy)]
@@ -22,4 +23,18 @@ pub enum GameAction {
     SetMouseDown(Option<WidgetId>),
     PlayAction(PlayPhaseAction),
     ContractAction(ContractPhaseAction),
+    /// Rewind to the state before the user's last action
+    Undo,
+    /// Re-apply the most recently undone user action
+    Redo,
+    /// Deal the next round of the current campaign
+    NextRound,
+    /// Purchase a program for the user between rounds of a campaign
+    BuyProgram(ProgramName),
+    /// Set the scroll offset of a scrollable widget
+    SetScroll(WidgetId, u16),
+    /// Exit the game
+    Quit,
+    /// Show the settings screen
+    OpenSettings,
 }
@@ -0,0 +1,50 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+use crate::contract_phase_data::ContractPhaseAction;
+use crate::play_phase_data::PlayPhaseAction;
+use crate::primitive::primitives::PlayerName;
+use crate::round_data::RoundData;
+use crate::snapshot::RoundSnapshot;
+
+/// A complete record of a round: its starting state followed by every action
+/// taken, in order.
+///
+/// The starting state includes the deal, trump suit, contracts and programs, so
+/// the round can be reproduced exactly by re-applying the actions.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GameRecord {
+    /// State of the round before any recorded action was taken.
+    pub initial: RoundSnapshot,
+    pub actions: Vec<RecordedAction>,
+    /// Number of times the user undid an action during this round. A scored
+    /// mode should treat a record with undos as unranked.
+    #[serde(default)]
+    pub undos: usize,
+}
+
+impl GameRecord {
+    pub fn new(data: &RoundData) -> Self {
+        Self { initial: data.into(), actions: vec![], undos: 0 }
+    }
+}
+
+/// An action taken in a round, along with the player who took it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum RecordedAction {
+    Contract(PlayerName, ContractPhaseAction),
+    Play(PlayerName, PlayPhaseAction),
+}
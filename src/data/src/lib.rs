@@ -12,13 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod campaign_data;
 pub mod contract_phase_data;
 pub mod delegate_data;
 pub mod design;
 pub mod game_action;
+pub mod game_record;
+pub mod pbn;
 pub mod play_phase_data;
 pub mod primitive;
 pub mod program_data;
 pub mod program_name;
 pub mod round_data;
+pub mod snapshot;
 pub mod widget_id;
@@ -0,0 +1,290 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Import and export of deals in Portable Bridge Notation (PBN).
+//!
+//! A PBN deal lists four hands clockwise, starting from the player named
+//! before the colon. Each hand lists its spades, hearts, diamonds and clubs
+//! separated by dots, e.g.
+//!
+//! ```text
+//! [Deal "N:AKQ2.J54.T98.K32 J987.AK3.Q762.A5 T6.Q972.AK43.J94 543.T86.J5.QT876"]
+//! ```
+//!
+//! The South hand is [PlayerName::User]. The trump suit is read from a `Trump`
+//! tag if present, or otherwise from the denomination of a `Contract` tag.
+//! Exported boards include a `Trump` tag when there is a trump suit.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use enumset::EnumSet;
+
+use crate::play_phase_data::Hands;
+use crate::primitive::primitives::{Card, PlayerName, Rank, Suit, NOTATION_SUITS};
+
+/// A deal read from a PBN file.
+#[derive(Debug, Clone)]
+pub struct PbnBoard {
+    pub hands: Hands,
+    /// Trump suit for this board, or None for notrump or when no trump suit
+    /// was specified.
+    pub trump: Option<Suit>,
+}
+
+/// Error returned when PBN text cannot be parsed.
+#[derive(Debug, Clone)]
+pub struct PbnError(pub String);
+
+impl Display for PbnError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid PBN: {}", self.0)
+    }
+}
+
+impl Error for PbnError {}
+
+/// Seats in the clockwise order PBN uses for listing hands.
+const SEATS: [PlayerName; 4] =
+    [PlayerName::North, PlayerName::East, PlayerName::User, PlayerName::West];
+
+/// Ranks in the order PBN lists them within a suit.
+const RANKS: [Rank; 13] = [
+    Rank::Ace,
+    Rank::King,
+    Rank::Queen,
+    Rank::Jack,
+    Rank::Ten,
+    Rank::Nine,
+    Rank::Eight,
+    Rank::Seven,
+    Rank::Six,
+    Rank::Five,
+    Rank::Four,
+    Rank::Three,
+    Rank::Two,
+];
+
+/// Parses every board with a `Deal` tag in a PBN file.
+///
+/// Tags other than `Deal`, `Trump` and `Contract` are ignored, as are
+/// auction, play and commentary sections.
+pub fn parse_boards(text: &str) -> Result<Vec<PbnBoard>, PbnError> {
+    let mut boards = vec![];
+    let mut trump_tag = false;
+    for line in text.lines().map(str::trim) {
+        let Some((name, value)) = parse_tag(line) else {
+            continue;
+        };
+        match name {
+            "Deal" => {
+                boards.push(PbnBoard { hands: parse_deal(value)?, trump: None });
+                trump_tag = false;
+            }
+            "Trump" => {
+                let board = boards.last_mut().ok_or_else(|| error("Trump tag before Deal"))?;
+                board.trump = parse_denomination(value)?;
+                trump_tag = true;
+            }
+            "Contract" if !trump_tag => {
+                let Some(board) = boards.last_mut() else {
+                    continue;
+                };
+                let denomination = value.trim_start_matches(|c: char| c.is_ascii_digit());
+                let denomination = denomination.trim_end_matches(['X', 'x']);
+                if !denomination.is_empty() && !value.eq_ignore_ascii_case("pass") {
+                    board.trump = parse_denomination(denomination)?;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if boards.is_empty() {
+        return Err(error("No Deal tag found"));
+    }
+    Ok(boards)
+}
+
+/// Formats a board as PBN tags.
+pub fn format_board(board: &PbnBoard) -> String {
+    let mut result = format!("[Deal \"{}\"]\n", format_deal(&board.hands));
+    if let Some(trump) = board.trump {
+        result.push_str(&format!("[Trump \"{}\"]\n", trump.letter()));
+    }
+    result
+}
+
+/// Parses the value of a PBN `Deal` tag, e.g. `"N:AKQ2.J54.T98.K32 ..."`.
+///
+/// All four hands must be given, with 13 different cards each.
+pub fn parse_deal(deal: &str) -> Result<Hands, PbnError> {
+    let (first, hands) =
+        deal.trim().split_once(':').ok_or_else(|| error("Deal is missing first seat"))?;
+    let first = match first.trim() {
+        "N" | "n" => 0,
+        "E" | "e" => 1,
+        "S" | "s" => 2,
+        "W" | "w" => 3,
+        other => return Err(error(format!("Unknown seat '{other}'"))),
+    };
+
+    let hands = hands.split_whitespace().collect::<Vec<_>>();
+    if hands.len() != 4 {
+        return Err(error(format!("Expected 4 hands, found {}", hands.len())));
+    }
+
+    let mut result =
+        Hands::new(EnumSet::empty(), EnumSet::empty(), EnumSet::empty(), EnumSet::empty());
+    let mut seen = EnumSet::<Card>::empty();
+    for (i, hand) in hands.into_iter().enumerate() {
+        let cards = parse_hand(hand)?;
+        if cards.len() != 13 {
+            return Err(error(format!("Hand '{hand}' has {} cards", cards.len())));
+        }
+        if !(seen & cards).is_empty() {
+            return Err(error(format!("Hand '{hand}' repeats a card from another hand")));
+        }
+        seen |= cards;
+        *result.hand_mut(SEATS[(first + i) % 4]) = cards;
+    }
+    Ok(result)
+}
+
+/// Formats hands as the value of a PBN `Deal` tag, starting from North.
+pub fn format_deal(hands: &Hands) -> String {
+    let hands = SEATS.iter().map(|&seat| format_hand(hands.hand(seat))).collect::<Vec<_>>();
+    format!("N:{}", hands.join(" "))
+}
+
+fn parse_hand(hand: &str) -> Result<EnumSet<Card>, PbnError> {
+    let suits = hand.split('.').collect::<Vec<_>>();
+    if suits.len() != 4 {
+        return Err(error(format!("Hand '{hand}' does not have 4 suits")));
+    }
+
+    let mut result = EnumSet::empty();
+    for (&suit, ranks) in NOTATION_SUITS.iter().zip(suits) {
+        for c in ranks.chars() {
+            let rank = c
+                .to_string()
+                .parse::<Rank>()
+                .map_err(|_| error(format!("Unknown rank '{c}' in hand '{hand}'")))?;
+            if !result.insert(Card::new(suit, rank)) {
+                return Err(error(format!("Hand '{hand}' repeats a card")));
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn format_hand(hand: EnumSet<Card>) -> String {
+    let suits = NOTATION_SUITS.iter().map(|&suit| {
+        RANKS
+            .iter()
+            .filter(|&&rank| hand.contains(Card::new(suit, rank)))
+            .map(|rank| rank.letter())
+            .collect::<String>()
+    });
+    suits.collect::<Vec<_>>().join(".")
+}
+
+/// Parses a `[Name "Value"]` tag line.
+fn parse_tag(line: &str) -> Option<(&str, &str)> {
+    let inner = line.strip_prefix('[')?.strip_suffix(']')?;
+    let (name, value) = inner.split_once(char::is_whitespace)?;
+    Some((name, value.trim().strip_prefix('"')?.strip_suffix('"')?))
+}
+
+/// Parses a trump denomination, returning None for notrump.
+fn parse_denomination(value: &str) -> Result<Option<Suit>, PbnError> {
+    match value.trim().to_ascii_uppercase().as_str() {
+        "S" => Ok(Some(Suit::Spades)),
+        "H" => Ok(Some(Suit::Hearts)),
+        "D" => Ok(Some(Suit::Diamonds)),
+        "C" => Ok(Some(Suit::Clubs)),
+        "NT" | "N" => Ok(None),
+        other => Err(error(format!("Unknown denomination '{other}'"))),
+    }
+}
+
+fn error(message: impl Into<String>) -> PbnError {
+    PbnError(message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEAL: &str = "N:AKQ2.J54.T98.K32 J987.AK3.Q762.A5 T6.Q972.AK43.J94 543.T86.J5.QT876";
+
+    #[test]
+    fn deal_round_trips() {
+        let hands = parse_deal(DEAL).expect("Invalid deal");
+        assert_eq!(format_deal(&hands), DEAL);
+        let south = Card::new(Suit::Spades, Rank::Ten);
+        assert!(hands.hand(PlayerName::User).contains(south));
+
+        let reparsed = parse_deal(&format_deal(&hands)).expect("Invalid deal");
+        for player in enum_iterator::all::<PlayerName>() {
+            assert_eq!(reparsed.hand(player), hands.hand(player));
+        }
+    }
+
+    #[test]
+    fn deal_can_start_from_any_seat() {
+        let rotated =
+            parse_deal("S:T6.Q972.AK43.J94 543.T86.J5.QT876 AKQ2.J54.T98.K32 J987.AK3.Q762.A5")
+                .expect("Invalid deal");
+        assert_eq!(format_deal(&rotated), DEAL);
+    }
+
+    #[test]
+    fn board_round_trips() {
+        let text = format!("[Event \"Test\"]\n[Deal \"{DEAL}\"]\n[Contract \"4H\"]\n");
+        let boards = parse_boards(&text).expect("Invalid PBN");
+        assert_eq!(boards.len(), 1);
+        assert_eq!(boards[0].trump, Some(Suit::Hearts));
+
+        let formatted = format_board(&boards[0]);
+        assert_eq!(formatted, format!("[Deal \"{DEAL}\"]\n[Trump \"H\"]\n"));
+        let reparsed = parse_boards(&formatted).expect("Invalid PBN");
+        assert_eq!(reparsed[0].trump, Some(Suit::Hearts));
+        assert_eq!(format_deal(&reparsed[0].hands), DEAL);
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        for deal in [
+            // Missing seat
+            "AKQ2.J54.T98.K32 J987.AK3.Q762.A5 T6.Q972.AK43.J94 543.T86.J5.QT876",
+            // Unknown seat
+            "X:AKQ2.J54.T98.K32 J987.AK3.Q762.A5 T6.Q972.AK43.J94 543.T86.J5.QT876",
+            // Three hands
+            "N:AKQ2.J54.T98.K32 J987.AK3.Q762.A5 T6.Q972.AK43.J94",
+            // Three suits
+            "N:AKQ2.J54.T98K32 J987.AK3.Q762.A5 T6.Q972.AK43.J94 543.T86.J5.QT876",
+            // Unknown rank
+            "N:AKQ2.J54.T98.K3X J987.AK3.Q762.A5 T6.Q972.AK43.J94 543.T86.J5.QT876",
+            // Twelve cards
+            "N:AKQ.J54.T98.K32 J987.AK3.Q762.A5 T6.Q972.AK43.J94 543.T86.J5.QT876",
+            // Card in two hands
+            "N:AKQ2.J54.T98.K32 A987.AK3.Q762.A5 T6.Q972.AK43.J94 543.T86.J5.QT876",
+        ] {
+            assert!(parse_deal(deal).is_err(), "{deal}");
+        }
+        assert!(parse_boards("[Event \"No deal\"]").is_err());
+        assert!(parse_boards(&format!("[Deal \"{DEAL}\"]\n[Trump \"Z\"]")).is_err());
+    }
+}
@@ -12,16 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Debug, Display, Formatter};
 
 use enumset::EnumSet;
+use serde::{Deserialize, Serialize};
 
 use crate::contract_phase_data::Contracts;
 use crate::delegate_data::{
     ActivationState, Context, HasPrograms, PlayPhaseDelegates, ProgramId, ProgramState,
 };
 use crate::game_action::GameAction;
-use crate::primitive::primitives::{Card, PlayerName, Suit};
+use crate::primitive::primitives::{
+    format_hand, parse_hand, Card, NotationError, PlayerName, Suit,
+};
 use crate::program_data::ProgramData;
 
 pub type TrickNumber = usize;
@@ -31,6 +34,8 @@ pub struct PlayPhaseData {
     pub current_trick: Trick,
     /// Player who is next to play, or None if this game has ended.
     pub turn: Option<PlayerName>,
+    /// Player who leads the first trick of this round.
+    pub leader: PlayerName,
     pub completed_tricks: Vec<CompletedTrick>,
     pub trump: Option<Suit>,
     pub contracts: Contracts,
@@ -84,7 +89,7 @@ impl HasPrograms for PlayPhaseData {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Hands {
     north: EnumSet<Card>,
     east: EnumSet<Card>,
@@ -128,7 +133,51 @@ impl Hands {
     }
 }
 
-#[derive(Debug, Clone)]
+/// Formats hands using [format_deal].
+impl Display for Hands {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", format_deal(self))
+    }
+}
+
+/// Players in the order hands are written in deal notation.
+const NOTATION_PLAYERS: [PlayerName; 4] =
+    [PlayerName::North, PlayerName::East, PlayerName::User, PlayerName::West];
+
+/// Formats a full deal as four hands separated by " / ", in the order North,
+/// East, User (South), West. Each hand is written using [format_hand].
+///
+/// [parse_deal] on the result returns the original hands.
+pub fn format_deal(hands: &Hands) -> String {
+    let hands = NOTATION_PLAYERS.iter().map(|&player| format_hand(hands.hand(player)));
+    hands.collect::<Vec<_>>().join(" / ")
+}
+
+/// Parses the notation produced by [format_deal].
+///
+/// Returns an error if a card appears in more than one hand. Hands are not
+/// required to be full, so partially played positions can be described.
+pub fn parse_deal(s: &str) -> Result<Hands, NotationError> {
+    let parts = s.split('/').collect::<Vec<_>>();
+    if parts.len() != 4 {
+        return Err(NotationError(format!("Expected 4 hands, found {}", parts.len())));
+    }
+
+    let mut hands =
+        Hands::new(EnumSet::empty(), EnumSet::empty(), EnumSet::empty(), EnumSet::empty());
+    let mut seen = EnumSet::<Card>::empty();
+    for (&player, part) in NOTATION_PLAYERS.iter().zip(parts) {
+        let hand = parse_hand(part)?;
+        if let Some(card) = (seen & hand).iter().next() {
+            return Err(NotationError(format!("Card {card} appears in more than one hand")));
+        }
+        seen |= hand;
+        *hands.hand_mut(player) = hand;
+    }
+    Ok(hands)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompletedTrick {
     /// Cards which were played in this trick.
     pub trick: Trick,
@@ -136,7 +185,7 @@ pub struct CompletedTrick {
     pub winner: PlayerName,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Trick {
     /// Cards played in this trick, in sequence
     pub cards: Vec<PlayedCard>,
@@ -150,7 +199,7 @@ impl Trick {
 }
 
 /// Represents a card played to a trick
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct PlayedCard {
     /// Player who played this card
     pub played_by: PlayerName,
@@ -158,7 +207,7 @@ pub struct PlayedCard {
     pub card: Card,
 }
 
-#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum PlayPhaseAction {
     PlayCard(Card),
     ActivateProgram(ProgramId),
@@ -182,3 +231,31 @@ impl Debug for PlayPhaseAction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deal_notation_round_trips() {
+        let cards = EnumSet::<Card>::all().iter().collect::<Vec<_>>();
+        let hand = |i: usize| cards.iter().skip(i).step_by(4).copied().collect::<EnumSet<_>>();
+        let hands = Hands::new(hand(0), hand(1), hand(2), hand(3));
+        let parsed = parse_deal(&format_deal(&hands)).expect("Invalid deal");
+        for player in enum_iterator::all::<PlayerName>() {
+            assert_eq!(parsed.hand(player), hands.hand(player));
+        }
+    }
+
+    #[test]
+    fn rejects_bad_deals() {
+        assert_eq!(
+            parse_deal("S:A / H:A / D:A").map(|_| ()),
+            Err(NotationError("Expected 4 hands, found 3".to_string()))
+        );
+        assert_eq!(
+            parse_deal("S:A / S:A / D:A / C:A").map(|_| ()),
+            Err(NotationError("Card A♠ appears in more than one hand".to_string()))
+        );
+    }
+}
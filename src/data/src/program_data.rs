@@ -21,6 +21,7 @@ use typed_builder::TypedBuilder;
 use crate::delegate_data::{
     ContractPhaseDelegates, PlayPhaseDelegates, ProgramId, ProgramState, RunPhaseDelegates,
 };
+use crate::primitive::numerics::Intel;
 use crate::primitive::primitives::PlayerName;
 use crate::program_name::ProgramName;
 
@@ -48,6 +49,8 @@ impl<T> ProgramData<T> {
 pub struct ProgramDefinition {
     pub name: ProgramName,
     pub text: Vec<Span<'static>>,
+    /// Price to purchase this program between rounds of a campaign
+    pub cost: Intel,
     #[builder(default, setter(strip_option))]
     pub contract_phase: Option<fn(&mut ContractPhaseDelegates)>,
     #[builder(default, setter(strip_option))]
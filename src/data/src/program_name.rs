@@ -15,7 +15,10 @@
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Sequence, Serialize, Deserialize)]
 pub enum ProgramName {
     Starfall,
     Obsidian,
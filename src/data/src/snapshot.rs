@@ -0,0 +1,98 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::contract_phase_data::{ContractPhaseData, ContractPhaseStep, Contracts};
+use crate::delegate_data::{ProgramId, ProgramState};
+use crate::play_phase_data::{CompletedTrick, Hands, PlayPhaseData, Trick};
+use crate::primitive::primitives::{PlayerName, Suit};
+use crate::program_name::ProgramName;
+use crate::round_data::RoundData;
+
+/// Serializable representation of a [RoundData].
+///
+/// Program delegates are function pointers and cannot be serialized, so a
+/// snapshot only records which programs each player owns. Delegates are
+/// registered again when the snapshot is restored.
+#[derive(Clone, Serialize, Deserialize)]
+pub enum RoundSnapshot {
+    ContractPhase(ContractPhaseSnapshot),
+    PlayPhase(PlayPhaseSnapshot),
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ContractPhaseSnapshot {
+    pub trump: Option<Suit>,
+    pub contracts: Contracts,
+    pub hands: Hands,
+    pub step: ContractPhaseStep,
+    pub leader: PlayerName,
+    pub all_programs: HashMap<PlayerName, Vec<ProgramName>>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PlayPhaseSnapshot {
+    pub current_trick: Trick,
+    pub turn: Option<PlayerName>,
+    pub leader: PlayerName,
+    pub completed_tricks: Vec<CompletedTrick>,
+    pub trump: Option<Suit>,
+    pub contracts: Contracts,
+    pub hands: Hands,
+    pub all_programs: HashMap<PlayerName, Vec<ProgramName>>,
+    pub program_state: Vec<(ProgramId, ProgramState)>,
+    pub activated: Vec<ProgramId>,
+}
+
+impl From<&RoundData> for RoundSnapshot {
+    fn from(data: &RoundData) -> Self {
+        match data {
+            RoundData::ContractPhase(contract) => Self::ContractPhase(contract.into()),
+            RoundData::PlayPhase(play) => Self::PlayPhase(play.into()),
+        }
+    }
+}
+
+impl From<&ContractPhaseData> for ContractPhaseSnapshot {
+    fn from(data: &ContractPhaseData) -> Self {
+        Self {
+            trump: data.trump,
+            contracts: data.contracts,
+            hands: data.hands.clone(),
+            step: data.step,
+            leader: data.leader,
+            all_programs: data.programs.all_programs.clone(),
+        }
+    }
+}
+
+impl From<&PlayPhaseData> for PlayPhaseSnapshot {
+    fn from(data: &PlayPhaseData) -> Self {
+        Self {
+            current_trick: data.current_trick.clone(),
+            turn: data.turn,
+            leader: data.leader,
+            completed_tricks: data.completed_tricks.clone(),
+            trump: data.trump,
+            contracts: data.contracts,
+            hands: data.hands.clone(),
+            all_programs: data.programs.all_programs.clone(),
+            program_state: data.programs.program_state.iter().map(|(&id, &s)| (id, s)).collect(),
+            activated: data.programs.activated.iter().copied().collect(),
+        }
+    }
+}
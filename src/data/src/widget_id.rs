@@ -14,12 +14,17 @@
 
 use crate::delegate_data::ProgramId;
 use crate::primitive::primitives::Card;
+use crate::program_name::ProgramName;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum WidgetId {
     CardView(Card),
     Program(ProgramId),
     IncreaseContractButton,
     DecreaseContractButton,
     AcceptContractButton,
+    NextRoundButton,
+    BuyProgramButton(ProgramName),
+    QuitButton,
+    TrickList,
 }
@@ -30,12 +30,18 @@ pub struct Button {
     #[builder(setter(into))]
     action: GameAction,
     id: WidgetId,
+    /// Characters to type to activate this button
+    #[builder(default, setter(strip_option, into))]
+    shortcut: Option<String>,
 }
 
 impl StatefulWidget for Button {
     type State = RenderContext;
 
     fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        if let Some(keys) = self.shortcut {
+            context.shortcut(self.id, keys);
+        }
         let hovered = context.hovered(self.id, area);
         let pressed = context.mouse_down(self.id, area);
         context.clicked(self.id, area, self.action);
@@ -58,7 +64,7 @@ impl StatefulWidget for Button {
     }
 }
 
-fn text_style(text: &str, hovered: bool, pressed: bool) -> Span {
+fn text_style(text: &str, hovered: bool, pressed: bool) -> Span<'_> {
     let mut result = text.fg(colors::white());
     result = if pressed { result.underlined() } else { result };
 
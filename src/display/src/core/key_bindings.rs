@@ -0,0 +1,66 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Serialize};
+
+/// Keys for global commands, interpreted by
+/// [crate::core::render_context::RenderContext].
+///
+/// Keys must be distinct and must not be [Self::is_reserved], see
+/// [Self::validate].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyBindings {
+    pub quit: char,
+    pub undo: char,
+    pub redo: char,
+    pub settings: char,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self { quit: 'x', undo: 'u', redo: 'r', settings: 'o' }
+    }
+}
+
+impl KeyBindings {
+    /// Returns true if `c` is used to type widget shortcuts and so cannot be
+    /// bound: digits, card rank & suit letters, 'p' for programs and '+' & '-'
+    /// for contract buttons.
+    pub fn is_reserved(c: char) -> bool {
+        c.is_ascii_digit() || "tjqkacdhsp+-".contains(c.to_ascii_lowercase())
+    }
+
+    /// Checks that no key is reserved or bound to more than one command,
+    /// returning a description of the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        let keys = self.keys();
+        for (i, (command, key)) in keys.iter().enumerate() {
+            if Self::is_reserved(*key) {
+                return Err(format!("'{key}' is used by shortcuts"));
+            }
+            if let Some((other, _)) = keys[..i].iter().find(|(_, k)| k == key) {
+                return Err(format!("'{key}' is already bound to {other}"));
+            }
+            if key.is_uppercase() {
+                return Err(format!("'{key}' for {command} must be lowercase"));
+            }
+        }
+        Ok(())
+    }
+
+    fn keys(&self) -> [(&'static str, char); 4] {
+        [("quit", self.quit), ("undo", self.undo), ("redo", self.redo), ("settings", self.settings)]
+    }
+}
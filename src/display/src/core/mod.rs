@@ -13,5 +13,6 @@
 // limitations under the License.
 
 pub mod button;
+pub mod key_bindings;
 pub mod layout;
 pub mod render_context;
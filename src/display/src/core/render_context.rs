@@ -12,13 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crossterm::event::{Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use std::collections::HashMap;
+
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind};
 use data::game_action::GameAction;
 use data::widget_id::WidgetId;
 use ratatui::layout::Position;
 use ratatui::prelude::*;
 use tracing::info;
 
+use crate::core::key_bindings::KeyBindings;
+
+/// State shared between widgets while rendering.
+///
+/// Widgets register themselves as focusable, and may register typed keyboard
+/// shortcuts, each frame. Keyboard input is interpreted against the widgets
+/// registered during the previous frame:
+///
+/// ```text
+/// Tab, Right        focus the next widget
+/// Shift+Tab, Left   focus the previous widget
+/// Enter             activate the focused widget
+/// letters/digits    type a widget shortcut, e.g. "qh" for the Q♥ card
+/// Esc, Ctrl+C       quit
+/// ```
+///
+/// Other global commands are configured via [KeyBindings].
 #[derive(Default)]
 pub struct RenderContext {
     event: Option<Event>,
@@ -26,19 +45,114 @@ pub struct RenderContext {
     current_mouse_down: Option<WidgetId>,
     exit: bool,
     action: Option<GameAction>,
+    scroll: HashMap<WidgetId, u16>,
+    focused: Option<WidgetId>,
+    /// Widget activated via the keyboard for the current frame
+    activated: Option<WidgetId>,
+    focus_order: Vec<WidgetId>,
+    previous_focus_order: Vec<WidgetId>,
+    shortcuts: Vec<(String, WidgetId)>,
+    previous_shortcuts: Vec<(String, WidgetId)>,
+    /// Characters typed so far towards a shortcut
+    typed: String,
+    key_bindings: KeyBindings,
 }
 
 impl RenderContext {
     pub fn set_last_event(&mut self, event: Option<Event>) {
         if let Some(Event::Key(e)) = event {
-            if e.kind == KeyEventKind::Press && e.code == KeyCode::Char('q') {
-                self.exit = true;
+            if e.kind == KeyEventKind::Press {
+                match e.code {
+                    KeyCode::Char('c') if e.modifiers.contains(KeyModifiers::CONTROL) => {
+                        self.exit = true
+                    }
+                    KeyCode::Esc => self.exit = true,
+                    KeyCode::Tab | KeyCode::Right => self.move_focus(1),
+                    KeyCode::BackTab | KeyCode::Left => self.move_focus(-1),
+                    KeyCode::Enter => self.activated = self.focused,
+                    KeyCode::Char(c) => self.type_char(c.to_ascii_lowercase()),
+                    _ => {}
+                }
             }
         }
 
         self.event = event;
     }
 
+    /// Handles a typed character, activating the matching widget once a
+    /// complete shortcut has been entered. Characters which cannot begin a
+    /// shortcut are handled as global commands.
+    fn type_char(&mut self, c: char) {
+        self.typed.push(c);
+        if !self.previous_shortcuts.iter().any(|(keys, _)| keys.starts_with(&self.typed)) {
+            self.typed = c.to_string();
+        }
+
+        if let Some(&(_, id)) = self.previous_shortcuts.iter().find(|(keys, _)| *keys == self.typed)
+        {
+            info!(?id, "Shortcut typed");
+            self.typed.clear();
+            self.focused = Some(id);
+            self.activated = Some(id);
+        } else if !self.previous_shortcuts.iter().any(|(keys, _)| keys.starts_with(&self.typed)) {
+            self.typed.clear();
+            let bindings = self.key_bindings;
+            if c == bindings.quit {
+                self.exit = true;
+            } else if c == bindings.undo {
+                self.action = Some(GameAction::Undo);
+            } else if c == bindings.redo {
+                self.action = Some(GameAction::Redo);
+            } else if c == bindings.settings {
+                self.action = Some(GameAction::OpenSettings);
+            }
+        }
+    }
+
+    fn move_focus(&mut self, delta: isize) {
+        let order = &self.previous_focus_order;
+        if order.is_empty() {
+            return;
+        }
+        let len = order.len() as isize;
+        let index = match self.focused.and_then(|id| order.iter().position(|&f| f == id)) {
+            Some(i) => (i as isize + delta).rem_euclid(len),
+            None if delta > 0 => 0,
+            None => len - 1,
+        };
+        self.focused = Some(order[index as usize]);
+    }
+
+    /// Registers a widget which can receive keyboard focus during this frame.
+    /// Widgets are focused in the order they are registered.
+    pub fn focusable(&mut self, id: WidgetId) {
+        if !self.focus_order.contains(&id) {
+            self.focus_order.push(id);
+        }
+    }
+
+    /// Registers a sequence of characters which activates the widget with the
+    /// provided [WidgetId] when typed.
+    pub fn shortcut(&mut self, id: WidgetId, keys: impl Into<String>) {
+        self.shortcuts.push((keys.into(), id));
+    }
+
+    pub fn focused(&self) -> Option<WidgetId> {
+        self.focused
+    }
+
+    pub fn key_bindings(&self) -> KeyBindings {
+        self.key_bindings
+    }
+
+    pub fn set_key_bindings(&mut self, key_bindings: KeyBindings) {
+        self.key_bindings = key_bindings;
+    }
+
+    pub fn current_hover(&self) -> Option<WidgetId> {
+        self.current_hover
+    }
+
     pub fn set_current_hover(&mut self, current: Option<WidgetId>) {
         self.current_hover = current;
     }
@@ -47,6 +161,14 @@ impl RenderContext {
         self.current_mouse_down = current;
     }
 
+    pub fn set_scroll(&mut self, id: WidgetId, offset: u16) {
+        self.scroll.insert(id, offset);
+    }
+
+    pub fn exit(&mut self) {
+        self.exit = true;
+    }
+
     pub fn should_exit(&self) -> bool {
         self.exit
     }
@@ -54,6 +176,12 @@ impl RenderContext {
     pub fn finish_render(&mut self) -> Option<GameAction> {
         let action = self.action;
         self.action = None;
+        self.activated = None;
+        self.previous_focus_order = std::mem::take(&mut self.focus_order);
+        self.previous_shortcuts = std::mem::take(&mut self.shortcuts);
+        if self.focused.is_some_and(|id| !self.previous_focus_order.contains(&id)) {
+            self.focused = None;
+        }
 
         if matches!(self.event, Some(Event::Mouse(e))
             if e.kind == MouseEventKind::Up(MouseButton::Left))
@@ -66,10 +194,13 @@ impl RenderContext {
         action
     }
 
+    /// Returns true if the widget is hovered by the mouse or has keyboard
+    /// focus.
     pub fn hovered(&mut self, id: WidgetId, area: Rect) -> bool {
         let current = self.current_hover == Some(id);
+        let focused = self.focused == Some(id);
         let Some(Event::Mouse(e)) = self.event else {
-            return current;
+            return current || focused;
         };
 
         if e.kind == MouseEventKind::Moved {
@@ -80,7 +211,7 @@ impl RenderContext {
             }
         }
 
-        current
+        current || focused
     }
 
     pub fn mouse_down(&mut self, id: WidgetId, area: Rect) -> bool {
@@ -100,7 +231,37 @@ impl RenderContext {
         current
     }
 
+    /// Returns the current scroll offset for a scrollable widget, clamped to
+    /// `max`. Scrolling the mouse wheel over `area` or pressing the up/down
+    /// arrow keys changes the offset for the next frame.
+    pub fn scroll_offset(&mut self, id: WidgetId, area: Rect, max: u16) -> u16 {
+        let current = self.scroll.get(&id).copied().unwrap_or_default().min(max);
+        let delta = match self.event {
+            Some(Event::Mouse(e)) if area.contains(Position::new(e.column, e.row)) => {
+                match e.kind {
+                    MouseEventKind::ScrollUp => -1,
+                    MouseEventKind::ScrollDown => 1,
+                    _ => 0,
+                }
+            }
+            Some(Event::Key(e)) if e.kind == KeyEventKind::Press => match e.code {
+                KeyCode::Up => -1,
+                KeyCode::Down => 1,
+                _ => 0,
+            },
+            _ => 0,
+        };
+        let target = current.saturating_add_signed(delta).min(max);
+        if target != current {
+            self.action = Some(GameAction::SetScroll(id, target));
+        }
+        current
+    }
+
+    /// Performs `action` if the widget was clicked or activated via the
+    /// keyboard. Clickable widgets are also registered as focusable.
     pub fn clicked(&mut self, id: WidgetId, area: Rect, action: impl Into<GameAction>) {
+        self.focusable(id);
         if matches!(self.event, Some(Event::Mouse(e))
             if e.kind == MouseEventKind::Up(MouseButton::Left)
                && area.contains(Position::new(e.column, e.row))
@@ -108,6 +269,9 @@ impl RenderContext {
         {
             info!(?id, "Widget clicked");
             self.action = Some(action.into());
+        } else if self.activated == Some(id) {
+            info!(?id, "Widget activated");
+            self.action = Some(action.into());
         }
     }
 }
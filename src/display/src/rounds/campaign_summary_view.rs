@@ -0,0 +1,197 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::campaign_data::CampaignData;
+use data::design::colors;
+use data::game_action::GameAction;
+use data::primitive::primitives::PlayerName;
+use data::program_name::ProgramName;
+use data::widget_id::WidgetId;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Rect, Size};
+use ratatui::prelude::*;
+use ratatui::symbols::border;
+use ratatui::widgets::{Block, Borders, Paragraph, StatefulWidget};
+use rules::program::programs;
+use rules::rounds::campaign;
+use typed_builder::TypedBuilder;
+
+use crate::core::button::Button;
+use crate::core::layout;
+use crate::core::render_context::RenderContext;
+
+pub const WIDTH: u16 = 56;
+pub const HEIGHT: u16 = 22;
+
+/// Shows contracts made and Intel earned in the most recent round of a
+/// campaign, along with each player's running total. Between rounds, also
+/// offers programs for the user to purchase with their available Intel.
+#[derive(TypedBuilder)]
+#[builder(builder_method(name = new))]
+pub struct CampaignSummaryView<'a> {
+    campaign: &'a CampaignData,
+}
+
+impl<'a> StatefulWidget for CampaignSummaryView<'a> {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let center = layout::centered_rect(Size { width: WIDTH, height: HEIGHT }, area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_set(border::PLAIN)
+            .border_style(colors::white());
+        let [top, shop, bottom] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Fill(1), Constraint::Length(4), Constraint::Length(4)])
+            .areas(block.inner(center));
+        block.render(center, buf);
+
+        let over = campaign::is_over(self.campaign);
+        let round_number = self.campaign.results.len();
+        let title = if over {
+            "Campaign Complete".to_string()
+        } else {
+            format!("Round {round_number} of {} Complete", self.campaign.max_rounds)
+        };
+        let mut lines = vec![
+            Line::from(title.fg(colors::white()).bold()),
+            Line::default(),
+            Line::from(
+                format!("{:<8}{:>10}{:>8}{:>10}{:>10}", "", "Contract", "Tricks", "Intel", "Total")
+                    .fg(colors::light_gray()),
+            ),
+        ];
+        if let Some(result) = self.campaign.results.last() {
+            for player in enum_iterator::all::<PlayerName>() {
+                let earned = format!("+{}✦", result.intel(player));
+                lines.push(Line::from(vec![
+                    format!(
+                        "{:<8}{:>10}{:>8}",
+                        player.to_string(),
+                        result.contracts.contract_number(player),
+                        result.tricks(player)
+                    )
+                    .fg(colors::white()),
+                    format!("{earned:>10}").fg(if result.made_contract(player) {
+                        colors::light_green()
+                    } else {
+                        colors::red()
+                    }),
+                    format!("{:>9}✦", self.campaign.total(player)).fg(colors::white()),
+                ]));
+            }
+        }
+        lines.push(Line::default());
+        lines.push(Line::from(
+            format!("Target: {}✦", self.campaign.target).fg(colors::light_gray()),
+        ));
+        let undos = self.campaign.undos();
+        if undos > 0 {
+            let round_undos = self.campaign.results.last().map_or(0, |r| r.undos);
+            lines.push(Line::from(
+                format!("Unranked: {round_undos} undos this round, {undos} total")
+                    .fg(colors::orange()),
+            ));
+        }
+        if over {
+            lines.push(Line::from(
+                format!("Winner: {}", winners(self.campaign)).fg(colors::yellow()),
+            ));
+        } else {
+            lines.push(Line::from(
+                format!("Available: {}✦", self.campaign.available(PlayerName::User))
+                    .fg(colors::white()),
+            ));
+            render_shop(self.campaign, shop, buf, context);
+        }
+        Paragraph::new(lines).alignment(Alignment::Center).render(top, buf);
+
+        let quit = Button::new().label("Quit").action(GameAction::Quit).id(WidgetId::QuitButton);
+        if over {
+            quit.build().render(
+                layout::centered_rect(Size { width: 20, height: 3 }, bottom),
+                buf,
+                context,
+            );
+        } else {
+            let [_, next, _, quit_area, _] = Layout::horizontal([
+                Constraint::Fill(1),
+                Constraint::Length(20),
+                Constraint::Length(2),
+                Constraint::Length(20),
+                Constraint::Fill(1),
+            ])
+            .areas(bottom);
+            Button::new()
+                .label("Next Round")
+                .action(GameAction::NextRound)
+                .id(WidgetId::NextRoundButton)
+                .build()
+                .render(layout::centered_rect(Size { width: 20, height: 3 }, next), buf, context);
+            quit.build().render(
+                layout::centered_rect(Size { width: 20, height: 3 }, quit_area),
+                buf,
+                context,
+            );
+        }
+    }
+}
+
+/// Renders a button to purchase each program the user does not yet own.
+fn render_shop(campaign: &CampaignData, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+    let programs = enum_iterator::all::<ProgramName>().collect::<Vec<_>>();
+    let areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(programs.iter().map(|_| Constraint::Fill(1)))
+        .spacing(1)
+        .split(area);
+    for (&program, &program_area) in programs.iter().zip(areas.iter()) {
+        let cost = programs::get(program).cost;
+        if campaign::can_buy(campaign, PlayerName::User, program) {
+            Button::new()
+                .label(format!("{program}\n{cost}✦"))
+                .action(GameAction::BuyProgram(program))
+                .id(WidgetId::BuyProgramButton(program))
+                .build()
+                .render(program_area, buf, context);
+        } else {
+            let status = if campaign.owns(PlayerName::User, program) {
+                "Owned".to_string()
+            } else {
+                format!("{cost}✦")
+            };
+            Paragraph::new(vec![Line::from(program.to_string()), Line::from(status)])
+                .fg(colors::dark_gray())
+                .alignment(Alignment::Center)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_set(border::DOUBLE)
+                        .border_style(colors::dark_gray()),
+                )
+                .render(program_area, buf);
+        }
+    }
+}
+
+/// Names of the players with the highest Intel total, joined with " & ".
+fn winners(campaign: &CampaignData) -> String {
+    let best = enum_iterator::all::<PlayerName>().map(|p| campaign.total(p)).max();
+    enum_iterator::all::<PlayerName>()
+        .filter(|&p| Some(campaign.total(p)) == best)
+        .map(|p| p.to_string())
+        .collect::<Vec<_>>()
+        .join(" & ")
+}
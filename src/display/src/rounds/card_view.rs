@@ -12,9 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use data::design::colors;
+use data::design::{colors, symbols};
 use data::game_action::GameAction;
-use data::primitive::primitives::Card;
+use data::primitive::primitives::{Card, Rank};
 use data::widget_id::WidgetId;
 use ratatui::layout::Offset;
 use ratatui::prelude::*;
@@ -33,6 +33,9 @@ pub struct CardView {
     debug_visible: bool,
     #[builder(default)]
     on_click: Option<GameAction>,
+    /// Draws a heavy border, e.g. to mark the winner of a trick
+    #[builder(default)]
+    highlighted: bool,
 }
 
 impl StatefulWidget for CardView {
@@ -42,8 +45,10 @@ impl StatefulWidget for CardView {
         Clear.render(area, buf);
         let block = Block::default()
             .borders(Borders::ALL)
-            .border_set(border::ROUNDED)
-            .border_style(Style::new().fg(if self.on_click.is_some() {
+            .border_set(if self.highlighted { border::THICK } else { border::ROUNDED })
+            .border_style(Style::new().fg(if self.highlighted {
+                colors::trick_winner()
+            } else if self.on_click.is_some() {
                 colors::can_activate()
             } else {
                 colors::white()
@@ -53,7 +58,13 @@ impl StatefulWidget for CardView {
         let pressed =
             self.on_click.is_some() && context.mouse_down(WidgetId::CardView(self.card), area);
         if let Some(action) = self.on_click {
-            context.clicked(WidgetId::CardView(self.card), area, action);
+            let id = WidgetId::CardView(self.card);
+            context.shortcut(id, self.card.notation().to_ascii_lowercase());
+            if self.card.rank() == Rank::Ten {
+                context
+                    .shortcut(id, format!("10{}", self.card.suit().letter()).to_ascii_lowercase());
+            }
+            context.clicked(id, area, action);
         }
 
         let inner = block.inner(area);
@@ -68,14 +79,14 @@ impl StatefulWidget for CardView {
                 vec![
                     Line::from(text_style(rank, self.card, hovered, pressed)),
                     Line::from(text_style(
-                        self.card.suit().to_string(),
+                        symbols::suit_glyph(self.card.suit()).to_string(),
                         self.card,
                         hovered,
                         pressed,
                     )),
                 ]
             } else {
-                vec![Line::from(text_style(self.card.to_string(), self.card, hovered, pressed))]
+                vec![Line::from(text_style(symbols::card(self.card), self.card, hovered, pressed))]
             };
             let [_, bottom] = Layout::default()
                 .direction(Direction::Vertical)
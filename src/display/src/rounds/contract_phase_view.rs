@@ -78,7 +78,7 @@ impl PlayAreaDelegate for ContractPhaseData {
     }
 }
 
-fn contract_string(data: &ContractPhaseData, name: PlayerName) -> Line {
+fn contract_string(data: &ContractPhaseData, name: PlayerName) -> Line<'_> {
     Line::from(
         format!("{name}: {} Tricks", data.contracts.contract_number(name)).fg(colors::white()),
     )
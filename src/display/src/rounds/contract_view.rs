@@ -15,7 +15,7 @@
 use std::cmp;
 
 use data::contract_phase_data::{ContractPhaseAction, ContractPhaseData, ContractPhaseStep};
-use data::design::colors;
+use data::design::{colors, symbols};
 use data::primitive::primitives::PlayerName;
 use data::widget_id::WidgetId;
 use ratatui::buffer::Buffer;
@@ -55,7 +55,7 @@ impl<'a> StatefulWidget for ContractView<'a> {
 
         let contract_number = self.data.contracts.contract_number(PlayerName::User);
         let mut lines = vec![Line::from(
-            format!("Trump Suit: {}", self.data.trump.map_or("NT".to_string(), |s| s.to_string()))
+            format!("Trump Suit: {}", symbols::trump(self.data.trump))
                 .fg(colors::trump(self.data.trump)),
         )];
         if self.data.step != ContractPhaseStep::ReadyToStart {
@@ -92,12 +92,14 @@ impl<'a> StatefulWidget for ContractView<'a> {
                     .label("Increase\nContract")
                     .action(ContractPhaseAction::SetUserContract(cmp::min(13, contract_number + 1)))
                     .id(WidgetId::IncreaseContractButton)
+                    .shortcut("+")
                     .build()
                     .render(left, buf, context);
                 Button::new()
                     .label("Decrease\nContract")
                     .action(ContractPhaseAction::SetUserContract(contract_number.saturating_sub(1)))
                     .id(WidgetId::DecreaseContractButton)
+                    .shortcut("-")
                     .build()
                     .render(middle, buf, context);
                 Button::new()
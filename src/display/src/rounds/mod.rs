@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod campaign_summary_view;
 pub mod card_view;
 pub mod contract_phase_view;
 pub mod contract_view;
@@ -20,5 +21,7 @@ pub mod play_area_delegate;
 pub mod play_area_view;
 pub mod play_phase_view;
 pub mod program_list_view;
+pub mod round_results_view;
+pub mod trick_list_view;
 pub mod trick_view;
 pub mod vertical_hand_view;
@@ -12,11 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use data::delegate_data::ProgramId;
-use data::design::colors;
+use data::delegate_data::{ActivationState, HasPrograms, ProgramId};
+use data::design::{colors, symbols};
 use data::game_action::GameAction;
 use data::play_phase_data::{PlayPhaseAction, PlayPhaseData};
 use data::primitive::primitives::{Card, PlayerName};
+use data::widget_id::WidgetId;
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Rect, Size};
 use ratatui::prelude::*;
@@ -24,17 +25,20 @@ use rules::play_phase::play_phase_queries;
 use rules::rounds::tricks;
 use typed_builder::TypedBuilder;
 
+use crate::core::layout;
 use crate::core::render_context::RenderContext;
 use crate::rounds::play_area_delegate::PlayAreaDelegate;
 use crate::rounds::play_area_view::PlayAreaView;
 use crate::rounds::program_list_view;
-use crate::rounds::program_list_view::ProgramListView;
-use crate::rounds::trick_view::TrickView;
+use crate::rounds::program_list_view::{ProgramDetailView, ProgramListView};
+use crate::rounds::trick_view::{TrickPresentation, TrickView};
 
 #[derive(TypedBuilder)]
 #[builder(builder_method(name = new))]
 pub struct PlayPhaseView<'a> {
     data: &'a PlayPhaseData,
+    #[builder(default)]
+    presentation: TrickPresentation,
 }
 
 impl<'a> StatefulWidget for PlayPhaseView<'a> {
@@ -42,17 +46,21 @@ impl<'a> StatefulWidget for PlayPhaseView<'a> {
 
     fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
         PlayAreaView::new()
-            .delegate(self.data)
+            .delegate(&self)
             .hands(&self.data.hands)
             .build()
             .render(area, buf, context)
     }
 }
 
-impl PlayAreaDelegate for PlayPhaseData {
+impl<'a> PlayAreaDelegate for PlayPhaseView<'a> {
     fn card_action(&self, player: PlayerName, card: Card) -> Option<GameAction> {
         if player == PlayerName::User
-            && play_phase_queries::can_perform_action(self, player, PlayPhaseAction::PlayCard(card))
+            && play_phase_queries::can_perform_action(
+                self.data,
+                player,
+                PlayPhaseAction::PlayCard(card),
+            )
         {
             Some(GameAction::PlayAction(PlayPhaseAction::PlayCard(card)))
         } else {
@@ -65,16 +73,18 @@ impl PlayAreaDelegate for PlayPhaseData {
     }
 
     fn render_top_status_bar(&self, area: Rect, buf: &mut Buffer, _: &mut RenderContext) {
-        contract_string(self, PlayerName::West).alignment(Alignment::Left).render(area, buf);
-        contract_string(self, PlayerName::North).alignment(Alignment::Center).render(area, buf);
-        contract_string(self, PlayerName::East).alignment(Alignment::Right).render(area, buf);
+        contract_string(self.data, PlayerName::West).alignment(Alignment::Left).render(area, buf);
+        contract_string(self.data, PlayerName::North)
+            .alignment(Alignment::Center)
+            .render(area, buf);
+        contract_string(self.data, PlayerName::East).alignment(Alignment::Right).render(area, buf);
     }
 
     fn render_bottom_status_bar(&self, area: Rect, buf: &mut Buffer, _: &mut RenderContext) {
-        contract_string(self, PlayerName::User).alignment(Alignment::Center).render(area, buf);
+        contract_string(self.data, PlayerName::User).alignment(Alignment::Center).render(area, buf);
         Line::from(
-            format!("Trump: {}", self.trump.map_or("NT".to_string(), |s| s.to_string()))
-                .fg(colors::trump(self.trump)),
+            format!("Trump: {}", symbols::trump(self.data.trump))
+                .fg(colors::trump(self.data.trump)),
         )
         .alignment(Alignment::Right)
         .render(area, buf);
@@ -87,41 +97,85 @@ impl PlayAreaDelegate for PlayPhaseData {
         buf: &mut Buffer,
         context: &mut RenderContext,
     ) {
-        let [program_list, tricks, _] = Layout::horizontal([
+        let [left, tricks, right] = Layout::horizontal([
             Constraint::Length(program_list_view::WIDTH),
             Constraint::Fill(1),
             Constraint::Length(program_list_view::WIDTH),
         ])
         .areas(area);
-        let programs = self
-            .programs
-            .all_programs
-            .get(&PlayerName::User)
-            .unwrap_or(&vec![])
-            .iter()
-            .map(|&name| ProgramId::new(name, PlayerName::User))
-            .collect();
-        ProgramListView::new().data(self).programs(programs).build().render(
-            program_list,
-            buf,
-            context,
-        );
+        let [west, user] = Layout::vertical([Constraint::Fill(1), Constraint::Fill(1)]).areas(left);
+        let [north, east] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Fill(1)]).areas(right);
+        for (player, program_list) in [
+            (PlayerName::User, user),
+            (PlayerName::West, west),
+            (PlayerName::North, north),
+            (PlayerName::East, east),
+        ] {
+            let programs = self
+                .data
+                .programs
+                .for_player(player)
+                .map(|name| ProgramId::new(name, player))
+                .collect();
+            ProgramListView::new()
+                .data(self.data)
+                .player(player)
+                .programs(programs)
+                .build()
+                .render(program_list, buf, context);
+        }
 
-        let trick = if self.current_trick.is_started() {
-            Some(self.current_trick.clone())
+        let trick = if self.data.current_trick.is_started() {
+            Some((self.data.current_trick.clone(), None))
         } else {
-            self.completed_tricks.last().map(|t| t.trick.clone())
+            self.data
+                .completed_tricks
+                .last()
+                .map(|t| (t.trick.clone(), self.presentation.show_winner.then_some(t.winner)))
         };
 
-        if let Some(t) = trick {
-            TrickView::new().trick(t).card_size(card_size).build().render(tricks, buf, context)
+        if let Some((t, winner)) = trick {
+            TrickView::new()
+                .trick(t)
+                .card_size(card_size)
+                .winner(winner)
+                .moving(self.presentation.moving)
+                .build()
+                .render(tricks, buf, context)
+        }
+
+        let program =
+            [context.current_hover(), context.focused()].into_iter().find_map(
+                |widget| match widget {
+                    Some(WidgetId::Program(id)) => Some(id),
+                    _ => None,
+                },
+            );
+        if let Some(id) = program {
+            ProgramDetailView::new()
+                .data(self.data)
+                .id(id)
+                .build()
+                .render(layout::centered_rect(program_list_view::DETAIL_SIZE, tricks), buf);
         }
     }
 }
 
-fn contract_string(data: &PlayPhaseData, name: PlayerName) -> Line {
-    Line::from(
-        format!("{name}: {}/{}", tricks::won(data, name), data.contracts.contract_number(name))
-            .fg(colors::white()),
+/// Shows the player's tricks won and contract, followed by the names of any of
+/// their programs which are currently active.
+fn contract_string(data: &PlayPhaseData, name: PlayerName) -> Line<'_> {
+    let mut spans = vec![format!(
+        "{name}: {}/{}",
+        tricks::won(data, name),
+        data.contracts.contract_number(name)
     )
+    .fg(colors::white())];
+    for program in data.programs.for_player(name) {
+        let id = ProgramId::new(program, name);
+        if data.activation_state(id) == ActivationState::CurrentlyActive {
+            spans.push(format!(" [{program}]").fg(colors::light_blue()));
+        }
+    }
+    Line::from(spans)
 }
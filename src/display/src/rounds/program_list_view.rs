@@ -16,9 +16,14 @@ use std::iter;
 
 use data::delegate_data::{ActivationState, HasPrograms, ProgramId};
 use data::design::colors;
-use data::play_phase_data::PlayPhaseAction;
+use data::play_phase_data::{PlayPhaseAction, PlayPhaseData};
+use data::primitive::primitives::PlayerName;
 use data::widget_id::WidgetId;
+use ratatui::layout::Size;
 use ratatui::prelude::*;
+use ratatui::symbols::border;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use rules::program::programs;
 use typed_builder::TypedBuilder;
 
 use crate::core::render_context::RenderContext;
@@ -29,6 +34,10 @@ pub const WIDTH: u16 = 8;
 #[builder(builder_method(name = new))]
 pub struct ProgramListView<'a, T: HasPrograms> {
     data: &'a T,
+    /// Player who owns the listed programs. The user's programs are aligned to
+    /// the bottom of the area, while AI agent programs are listed from the top
+    /// under the agent's name.
+    player: PlayerName,
     programs: Vec<ProgramId>,
 }
 
@@ -36,13 +45,24 @@ impl<'a, T: HasPrograms> StatefulWidget for ProgramListView<'a, T> {
     type State = RenderContext;
 
     fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
-        let split = Layout::vertical(
-            iter::once(Constraint::Fill(1))
-                .chain(self.programs.iter().map(|_| Constraint::Length(1))),
-        )
-        .split(area);
+        if self.programs.is_empty() {
+            return;
+        }
+
+        let rows = self.programs.iter().map(|_| Constraint::Length(1));
+        let split = if self.player.is_user() {
+            Layout::vertical(iter::once(Constraint::Fill(1)).chain(rows)).split(area)
+        } else {
+            Layout::vertical(iter::once(Constraint::Length(1)).chain(rows)).split(area)
+        };
+        if self.player.is_agent() {
+            Line::styled(self.player.to_string(), colors::dark_gray()).render(split[0], buf);
+        }
 
         for (i, &program) in self.programs.iter().enumerate() {
+            if self.player.is_user() {
+                context.shortcut(WidgetId::Program(program), format!("p{}", i + 1));
+            }
             ProgramNameView::new()
                 .id(program)
                 .activation(self.data.activation_state(program))
@@ -63,15 +83,20 @@ impl StatefulWidget for ProgramNameView {
     type State = RenderContext;
 
     fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        // Only the user's own programs can be activated from the UI, but any
+        // program can be hovered to show its [ProgramDetailView].
+        let interactive = self.id.owner.is_user() && self.activation.can_activate();
         let widget_id = WidgetId::Program(self.id);
-        let hovered = self.activation.can_activate() && context.hovered(widget_id, area);
-        let pressed = self.activation.can_activate() && context.mouse_down(widget_id, area);
-        if self.activation.can_activate() {
+        context.focusable(widget_id);
+        let hovered = context.hovered(widget_id, area) && interactive;
+        let pressed = interactive && context.mouse_down(widget_id, area);
+        if interactive {
             context.clicked(widget_id, area, PlayPhaseAction::ActivateProgram(self.id));
         }
 
         let mut style = match self.activation {
             ActivationState::CannotActivate => Style::new().fg(colors::white()).bold(),
+            ActivationState::CanActivate if !interactive => Style::new().fg(colors::white()).bold(),
             ActivationState::CanActivate => {
                 Style::new().fg(colors::can_activate()).bold().underlined()
             }
@@ -90,3 +115,65 @@ impl StatefulWidget for ProgramNameView {
         Line::styled(self.id.name.to_string(), style).render(area, buf);
     }
 }
+
+/// Size of the area required to render a [ProgramDetailView]
+pub const DETAIL_SIZE: Size = Size { width: 34, height: 8 };
+
+/// Panel describing a program: its rules text, its current [ActivationState],
+/// and why it cannot currently be activated, if applicable.
+#[derive(TypedBuilder)]
+#[builder(builder_method(name = new))]
+pub struct ProgramDetailView<'a> {
+    data: &'a PlayPhaseData,
+    id: ProgramId,
+}
+
+impl<'a> Widget for ProgramDetailView<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let definition = programs::get(self.id.name);
+        let activation = self.data.activation_state(self.id);
+        let (status, color) = match activation {
+            ActivationState::CannotActivate => {
+                (format!("Unavailable: {}", unavailable_reason(self.data, self.id)), colors::red())
+            }
+            ActivationState::CanActivate if self.id.owner.is_user() => {
+                ("Ready: click to activate".to_string(), colors::can_activate())
+            }
+            ActivationState::CanActivate => ("Ready".to_string(), colors::can_activate()),
+            ActivationState::CurrentlyActive => ("Active".to_string(), colors::light_blue()),
+            ActivationState::PreviouslyActivated => {
+                ("Already used this round".to_string(), colors::light_gray())
+            }
+        };
+
+        Clear.render(area, buf);
+        Paragraph::new(vec![
+            Line::from(definition.text.clone()),
+            Line::default(),
+            Line::from(status.fg(color)),
+        ])
+        .fg(colors::white())
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .title(format!("{} ({})", self.id.name, self.id.owner))
+                .borders(Borders::ALL)
+                .border_set(border::PLAIN)
+                .border_style(colors::white()),
+        )
+        .render(area, buf);
+    }
+}
+
+/// Describes why a program in the [ActivationState::CannotActivate] state
+/// cannot be activated.
+fn unavailable_reason(data: &PlayPhaseData, id: ProgramId) -> String {
+    match data.turn {
+        None => "the round is over".to_string(),
+        Some(_) if !data.is_turn(id.owner) && id.owner.is_user() => {
+            "only during your turn".to_string()
+        }
+        Some(_) if !data.is_turn(id.owner) => format!("only during {}'s turn", id.owner),
+        Some(_) => "its condition is not met".to_string(),
+    }
+}
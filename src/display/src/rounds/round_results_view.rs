@@ -0,0 +1,96 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::campaign_data::CampaignData;
+use data::delegate_data::ProgramId;
+use data::design::colors;
+use data::play_phase_data::PlayPhaseData;
+use ratatui::prelude::*;
+use ratatui::symbols::border;
+use ratatui::widgets::{Block, Borders};
+use typed_builder::TypedBuilder;
+
+use crate::core::render_context::RenderContext;
+use crate::rounds::campaign_summary_view::{self, CampaignSummaryView};
+use crate::rounds::trick_list_view::TrickListView;
+
+/// Width of the panel listing programs used and tricks played
+const TRICKS_WIDTH: u16 = 26;
+
+/// Shown once a round is complete: the campaign summary alongside the programs
+/// activated during the round and a trick-by-trick breakdown.
+#[derive(TypedBuilder)]
+#[builder(builder_method(name = new))]
+pub struct RoundResultsView<'a> {
+    data: &'a PlayPhaseData,
+    campaign: &'a CampaignData,
+}
+
+impl<'a> StatefulWidget for RoundResultsView<'a> {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let tricks_width =
+            TRICKS_WIDTH.min(area.width.saturating_sub(campaign_summary_view::WIDTH));
+        let width = campaign_summary_view::WIDTH + tricks_width;
+        let height = campaign_summary_view::HEIGHT.min(area.height);
+        let [summary, tricks] = Layout::horizontal([
+            Constraint::Length(campaign_summary_view::WIDTH),
+            Constraint::Length(tricks_width),
+        ])
+        .areas(Rect {
+            x: area.x + area.width.saturating_sub(width) / 2,
+            y: area.y + area.height.saturating_sub(height) / 2,
+            width: width.min(area.width),
+            height,
+        });
+
+        CampaignSummaryView::new().campaign(self.campaign).build().render(summary, buf, context);
+
+        let block = Block::default()
+            .title("Tricks ↑↓")
+            .borders(Borders::ALL)
+            .border_set(border::PLAIN)
+            .border_style(colors::white());
+        let inner = block.inner(tricks);
+        block.render(tricks, buf);
+
+        let programs = activated_programs(self.data);
+        let [programs_area, list] = Layout::vertical([
+            Constraint::Length(programs.len().max(1) as u16 + 2),
+            Constraint::Fill(1),
+        ])
+        .areas(inner);
+        let mut lines = vec![Line::from("Programs Used".fg(colors::light_gray()))];
+        if programs.is_empty() {
+            lines.push(Line::from("None".fg(colors::white())));
+        }
+        for id in programs {
+            lines.push(Line::from(vec![
+                format!("{:<6}", id.owner.to_string()).fg(colors::white()),
+                id.name.to_string().fg(colors::light_blue()),
+            ]));
+        }
+        Text::from(lines).render(programs_area, buf);
+
+        TrickListView::new().tricks(&self.data.completed_tricks).build().render(list, buf, context);
+    }
+}
+
+/// Programs activated during the round, ordered by owner and then name
+fn activated_programs(data: &PlayPhaseData) -> Vec<ProgramId> {
+    let mut result = data.programs.activated.iter().copied().collect::<Vec<_>>();
+    result.sort_by_key(|id| (id.owner, id.name.to_string()));
+    result
+}
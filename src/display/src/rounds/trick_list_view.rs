@@ -0,0 +1,66 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use data::design::{colors, symbols};
+use data::play_phase_data::CompletedTrick;
+use data::widget_id::WidgetId;
+use ratatui::prelude::*;
+use typed_builder::TypedBuilder;
+
+use crate::core::render_context::RenderContext;
+
+/// Scrollable list of completed tricks, one per line, showing the cards played
+/// in order with the winning card highlighted.
+#[derive(TypedBuilder)]
+#[builder(builder_method(name = new))]
+pub struct TrickListView<'a> {
+    tricks: &'a [CompletedTrick],
+}
+
+impl<'a> StatefulWidget for TrickListView<'a> {
+    type State = RenderContext;
+
+    fn render(self, area: Rect, buf: &mut Buffer, context: &mut RenderContext) {
+        let max =
+            u16::try_from(self.tricks.len()).expect("Too many tricks").saturating_sub(area.height);
+        let offset = context.scroll_offset(WidgetId::TrickList, area, max);
+
+        let lines = self
+            .tricks
+            .iter()
+            .enumerate()
+            .skip(offset as usize)
+            .take(area.height as usize)
+            .map(|(i, completed)| trick_line(i + 1, completed))
+            .collect::<Vec<_>>();
+        Text::from(lines).render(area, buf);
+    }
+}
+
+fn trick_line(number: usize, completed: &CompletedTrick) -> Line<'static> {
+    let mut spans = vec![format!("{number:>2} ").fg(colors::light_gray())];
+    for played in &completed.trick.cards {
+        let card = played.card;
+        let text = format!("{}{}", card.rank().letter(), symbols::suit_glyph(card.suit()))
+            .fg(colors::suit(card.suit()));
+        spans.push(if played.played_by == completed.winner {
+            text.bg(colors::selected()).bold()
+        } else {
+            text
+        });
+        spans.push(" ".into());
+    }
+    spans.push(format!("{:?}", completed.winner).fg(colors::white()).bold());
+    Line::from(spans)
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use data::play_phase_data::{PlayedCard, Trick};
-use data::primitive::primitives::PlayerName;
+use data::primitive::primitives::{Card, PlayerName};
 use ratatui::layout::{Offset, Size};
 use ratatui::prelude::*;
 use typed_builder::TypedBuilder;
@@ -22,11 +22,27 @@ use crate::core::layout;
 use crate::core::render_context::RenderContext;
 use crate::rounds::card_view::CardView;
 
+/// Transient state used to animate play in a [TrickView]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TrickPresentation {
+    /// A card moving into the trick and the fraction of its movement which is
+    /// complete, from 0.0 (at the edge of the play area nearest its player's
+    /// hand) to 1.0 (in place).
+    pub moving: Option<(Card, f64)>,
+    /// Highlight the winner of the displayed trick, if it is complete
+    pub show_winner: bool,
+}
+
 #[derive(TypedBuilder)]
 #[builder(builder_method(name = new))]
 pub struct TrickView {
     trick: Trick,
     card_size: Size,
+    /// Player whose card should be highlighted as the trick winner
+    #[builder(default)]
+    winner: Option<PlayerName>,
+    #[builder(default)]
+    moving: Option<(Card, f64)>,
 }
 
 impl TrickView {
@@ -51,7 +67,18 @@ impl TrickView {
             PlayerName::West => center
                 .offset(Offset { x: (self.card_size.width as f64 * -offset).round() as i32, y: 0 }),
         };
-        CardView::new().card(card.card).visible(true).build().render(target, buf, context);
+        let target = match self.moving {
+            Some((moving, progress)) if moving == card.card => {
+                interpolate(start_rect(card.played_by, target, parent_area), target, progress)
+            }
+            _ => target,
+        };
+        CardView::new()
+            .card(card.card)
+            .visible(true)
+            .highlighted(self.winner == Some(card.played_by))
+            .build()
+            .render(target, buf, context);
     }
 }
 
@@ -64,3 +91,20 @@ impl StatefulWidget for TrickView {
         }
     }
 }
+
+/// Position from which a card played by `player` begins moving towards
+/// `target`: the edge of `area` nearest to that player's hand.
+fn start_rect(player: PlayerName, target: Rect, area: Rect) -> Rect {
+    match player {
+        PlayerName::North => Rect { y: area.top(), ..target },
+        PlayerName::East => Rect { x: area.right().saturating_sub(target.width), ..target },
+        PlayerName::User => Rect { y: area.bottom().saturating_sub(target.height), ..target },
+        PlayerName::West => Rect { x: area.left(), ..target },
+    }
+}
+
+fn interpolate(from: Rect, to: Rect, progress: f64) -> Rect {
+    let lerp =
+        |a: u16, b: u16| (a as f64 + (b as f64 - a as f64) * progress.clamp(0.0, 1.0)).round();
+    Rect { x: lerp(from.x, to.x) as u16, y: lerp(from.y, to.y) as u16, ..to }
+}
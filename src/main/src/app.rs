@@ -17,39 +17,115 @@ use std::time::Duration;
 use ai::ai_agent_action;
 use color_eyre::Result;
 use crossterm::event;
-use data::contract_phase_data::ContractPhaseStep;
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use data::campaign_data::CampaignData;
+use data::contract_phase_data::{ContractPhaseAction, ContractPhaseStep};
+use data::design::theme;
 use data::game_action::GameAction;
+use data::game_record::{GameRecord, RecordedAction};
+use data::pbn::PbnBoard;
+use data::play_phase_data::{PlayPhaseAction, PlayedCard};
 use data::primitive::primitives::PlayerName;
 use data::round_data::RoundData;
+use data::widget_id::WidgetId;
 use display::core::render_context::RenderContext;
 use display::rounds::contract_phase_view::ContractPhaseView;
 use display::rounds::play_phase_view::PlayPhaseView;
+use display::rounds::round_results_view::RoundResultsView;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use ratatui::prelude::*;
 use ratatui::widgets::{Paragraph, Wrap};
 use rules::contract_phase::contract_phase_actions;
 use rules::play_phase::play_phase_actions;
-use rules::rounds::new_round;
-use tracing::info;
+use rules::rounds::{campaign, new_round};
+use tracing::{error, info};
 
+use crate::history::History;
+use crate::presentation::Presentation;
+use crate::settings::Settings;
+use crate::settings_screen::{Outcome, SettingsScreen};
 use crate::tui::Tui;
+use crate::{game_records, saved_round, settings};
 
-pub fn run(tui: &mut Tui) -> Result<()> {
-    let mut data = new_round::create(&mut rand::thread_rng());
+/// Runs a campaign of rounds. If a `board` is provided, a new campaign is
+/// started with that deal instead of offering to resume a saved round, and the
+/// campaign is not saved on quit so it never replaces the saved round.
+pub fn run(
+    tui: &mut Tui,
+    seed: u64,
+    board: Option<PbnBoard>,
+    campaign: CampaignData,
+    mut settings: Settings,
+) -> Result<()> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let from_pbn = board.is_some();
+    let saved = if board.is_none() && saved_round::exists() && prompt_resume(tui)? {
+        saved_round::load()
+    } else {
+        None
+    };
+    let (mut data, mut record, mut campaign) = saved.unwrap_or_else(|| {
+        let data = match board {
+            Some(board) => new_round::deal(
+                board.hands,
+                board.trump,
+                campaign.leader,
+                campaign::loadouts(&campaign, &mut rng),
+            ),
+            None => campaign::deal(&campaign, &mut rng),
+        };
+        let record = GameRecord::new(&data);
+        (data, record, campaign)
+    });
     let mut context = RenderContext::default();
-    let mut ai_search_running = false;
+    context.set_key_bindings(settings.key_bindings);
+    theme::set(settings.theme());
+    let mut history = History::default();
+    let mut settings_screen: Option<SettingsScreen> = None;
+    let mut presentation = Presentation::new(&settings);
+    let mut ai_search_running = resume_agent_search(&data, &settings, &mut rng);
     while !context.should_exit() {
-        context.set_last_event(if event::poll(Duration::from_millis(16))? {
-            Some(event::read()?)
+        let event =
+            if event::poll(Duration::from_millis(16))? { Some(event::read()?) } else { None };
+        if let Some(screen) = &mut settings_screen {
+            // The settings screen is modal, so the game does not see events
+            // while it is open
+            match event.as_ref().and_then(|e| screen.handle_event(e)) {
+                Some(Outcome::Save(updated)) => {
+                    if let Err(e) = settings::save(&updated) {
+                        error!(?e, "Unable to save settings");
+                    }
+                    context.set_key_bindings(updated.key_bindings);
+                    theme::set(updated.theme());
+                    presentation.configure(&updated);
+                    settings = updated;
+                    settings_screen = None;
+                }
+                Some(Outcome::Cancel) => {
+                    settings_screen = None;
+                }
+                None => {}
+            }
+            context.set_last_event(None);
         } else {
-            None
-        });
+            context.set_last_event(event);
+        }
         tui.draw(|frame| loop {
-            frame.render_stateful_widget(App { data: &data }, frame.size(), &mut context);
+            frame.render_stateful_widget(
+                App { data: &data, campaign: &campaign, presentation: &presentation },
+                frame.size(),
+                &mut context,
+            );
+            if let Some(screen) = &settings_screen {
+                frame.render_widget(screen, frame.size());
+            }
 
             let action = if let Some(action) = context.finish_render() {
                 action
-            } else if let Some(action) = ai_agent_action::poll_action() {
-                ai_search_running = false;
+            } else if let Some(action) =
+                next_ai_action(&mut presentation, settings_screen.is_some(), &mut ai_search_running)
+            {
                 action
             } else {
                 break;
@@ -60,29 +136,97 @@ pub fn run(tui: &mut Tui) -> Result<()> {
                     let Some(current_player) = play_data.turn else {
                         break;
                     };
+                    if current_player == PlayerName::User {
+                        history.push(&RoundData::PlayPhase(play_data.clone()), &record);
+                    }
                     play_phase_actions::handle_action(play_data, current_player, a);
+                    if let PlayPhaseAction::PlayCard(card) = a {
+                        presentation.card_played(
+                            PlayedCard { played_by: current_player, card },
+                            !play_data.current_trick.is_started(),
+                        );
+                    }
+                    record.actions.push(RecordedAction::Play(current_player, a));
                     let Some(next_player) = play_data.turn else {
+                        if let Err(e) = game_records::write(&record) {
+                            error!(?e, "Unable to write game record");
+                        }
+                        campaign::record_result(&mut campaign, play_data, record.undos);
+                        history = History::default();
                         continue;
                     };
                     if next_player.is_agent() && !ai_search_running {
                         ai_search_running = true;
-                        ai_agent_action::initiate_selection(play_data.clone());
+                        ai_agent_action::initiate_selection(
+                            play_data.clone(),
+                            settings.agent,
+                            settings.difficulty.think_time(),
+                            rng.gen(),
+                        );
                     }
                 }
                 (RoundData::ContractPhase(contract_data), GameAction::ContractAction(a)) => {
                     info!(?a, "Handling ContractPhaseAction");
+                    if !matches!(a, ContractPhaseAction::SetAgentContracts { .. }) {
+                        history.push(&RoundData::ContractPhase(contract_data.clone()), &record);
+                    }
                     let result =
                         contract_phase_actions::handle_action(contract_data, PlayerName::User, a);
+                    record.actions.push(RecordedAction::Contract(PlayerName::User, a));
                     if contract_data.step == ContractPhaseStep::AwaitingAgentContracts
                         && !ai_search_running
                     {
                         ai_search_running = true;
-                        ai_agent_action::populate_agent_contracts(contract_data.clone());
+                        ai_agent_action::populate_agent_contracts(contract_data.clone(), rng.gen());
                     }
                     if let Some(r) = result {
                         data = r;
+                        if !ai_search_running {
+                            ai_search_running = resume_agent_search(&data, &settings, &mut rng);
+                        }
                     }
                 }
+                (RoundData::PlayPhase(play_data), GameAction::NextRound) => {
+                    if play_data.turn.is_none() && !campaign::is_over(&campaign) {
+                        data = campaign::next_round(&mut campaign, &mut rng);
+                        record = GameRecord::new(&data);
+                        history = History::default();
+                        presentation.clear();
+                        context.set_scroll(WidgetId::TrickList, 0);
+                    }
+                }
+                (RoundData::PlayPhase(play_data), GameAction::BuyProgram(program)) => {
+                    if play_data.turn.is_none()
+                        && campaign::can_buy(&campaign, PlayerName::User, program)
+                    {
+                        campaign::buy(&mut campaign, PlayerName::User, program);
+                    }
+                }
+                (_, GameAction::Undo) => {
+                    if history.undo(&mut data, &mut record) {
+                        info!("Undid user action");
+                        ai_agent_action::cancel();
+                        presentation.clear();
+                        ai_search_running = resume_agent_search(&data, &settings, &mut rng);
+                    }
+                }
+                (_, GameAction::Redo) => {
+                    if history.redo(&mut data, &mut record) {
+                        info!("Redid user action");
+                        ai_agent_action::cancel();
+                        presentation.clear();
+                        ai_search_running = resume_agent_search(&data, &settings, &mut rng);
+                    }
+                }
+                (_, GameAction::SetScroll(id, offset)) => {
+                    context.set_scroll(id, offset);
+                }
+                (_, GameAction::OpenSettings) => {
+                    settings_screen = Some(SettingsScreen::new(&settings));
+                }
+                (_, GameAction::Quit) => {
+                    context.exit();
+                }
                 (_, GameAction::SetHover(id)) => {
                     context.set_current_hover(id);
                 }
@@ -95,11 +239,95 @@ pub fn run(tui: &mut Tui) -> Result<()> {
             };
         })?;
     }
+
+    if from_pbn {
+        info!("Not saving campaign started from a PBN deal");
+    } else if campaign::is_over(&campaign) {
+        saved_round::delete()?;
+    } else {
+        saved_round::save(&data, &record, &campaign)?;
+    }
     Ok(())
 }
 
+/// Asks the user whether to resume their saved round. Returns true if they
+/// chose to resume.
+fn prompt_resume(tui: &mut Tui) -> Result<bool> {
+    loop {
+        tui.draw(|frame| {
+            Paragraph::new(vec![
+                Line::from("A saved campaign was found."),
+                Line::from("Resume it? (y/n)"),
+            ])
+            .alignment(Alignment::Center)
+            .render(frame.size(), frame.buffer_mut());
+        })?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('y') | KeyCode::Enter => return Ok(true),
+                KeyCode::Char('n') | KeyCode::Esc => return Ok(false),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Starts any AI agent search which is needed to continue `data`, e.g. after
+/// resuming a saved round or undoing an action. Returns true if a search was
+/// started.
+fn resume_agent_search(data: &RoundData, settings: &Settings, rng: &mut StdRng) -> bool {
+    match data {
+        RoundData::ContractPhase(contract_data)
+            if contract_data.step == ContractPhaseStep::AwaitingAgentContracts =>
+        {
+            ai_agent_action::populate_agent_contracts(contract_data.clone(), rng.gen());
+            true
+        }
+        RoundData::PlayPhase(play_data) if play_data.turn.is_some_and(|p| p.is_agent()) => {
+            ai_agent_action::initiate_selection(
+                play_data.clone(),
+                settings.agent,
+                settings.difficulty.think_time(),
+                rng.gen(),
+            );
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Returns the next AI agent action to apply, if any.
+///
+/// Play actions are queued in `presentation` so they are paced for display and
+/// wait while `paused`, e.g. while the settings screen is open. Other actions
+/// are returned as soon as the agent selects them.
+fn next_ai_action(
+    presentation: &mut Presentation,
+    paused: bool,
+    ai_search_running: &mut bool,
+) -> Option<GameAction> {
+    if let Some(action) = ai_agent_action::poll_action() {
+        *ai_search_running = false;
+        if !matches!(action, GameAction::PlayAction(_)) {
+            return Some(action);
+        }
+        presentation.push(action);
+    }
+    if paused {
+        None
+    } else {
+        presentation.pop()
+    }
+}
+
 pub struct App<'a> {
     pub data: &'a RoundData,
+    pub campaign: &'a CampaignData,
+    pub presentation: &'a Presentation,
 }
 
 impl<'a> StatefulWidget for App<'a> {
@@ -112,7 +340,7 @@ impl<'a> StatefulWidget for App<'a> {
                     "Error: The minimum terminal size for this game is 80 columns by 24 rows!",
                 ),
                 Line::from(format!("Your terminal is {} by {}.", area.width, area.height)),
-                Line::from("Press 'q' to quit."),
+                Line::from(format!("Press '{}' or Esc to quit.", context.key_bindings().quit)),
             ])
             .wrap(Wrap { trim: false })
             .alignment(Alignment::Center)
@@ -122,8 +350,21 @@ impl<'a> StatefulWidget for App<'a> {
                 RoundData::ContractPhase(c) => {
                     ContractPhaseView::new().data(c).build().render(area, buf, context);
                 }
+                RoundData::PlayPhase(p)
+                    if p.turn.is_none() && !self.presentation.is_showing_trick() =>
+                {
+                    RoundResultsView::new()
+                        .data(p)
+                        .campaign(self.campaign)
+                        .build()
+                        .render(area, buf, context);
+                }
                 RoundData::PlayPhase(p) => {
-                    PlayPhaseView::new().data(p).build().render(area, buf, context);
+                    PlayPhaseView::new()
+                        .data(p)
+                        .presentation(self.presentation.trick())
+                        .build()
+                        .render(area, buf, context);
                 }
             }
         }
@@ -12,10 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use clap::Parser;
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
 
 use crate::utils::version;
 
 #[derive(Parser, Debug)]
 #[command(version = version(), about)]
-pub struct Cli {}
+pub struct Cli {
+    /// Seed for dealing cards and for AI agent decisions. A random seed is
+    /// chosen if not provided.
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Start a new round with the first deal in this PBN file. Campaigns
+    /// started this way are not saved.
+    #[arg(long)]
+    pub pbn: Option<PathBuf>,
+    /// Number of rounds to play in a new campaign
+    #[arg(long, default_value_t = 8)]
+    pub rounds: usize,
+    /// Intel total which ends a new campaign when reached by any player
+    #[arg(long, default_value_t = 1000)]
+    pub target_intel: u32,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Replays a game record, checking that every action in it is legal
+    Replay {
+        /// Path to the game record file
+        path: PathBuf,
+    },
+}
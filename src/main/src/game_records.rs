@@ -0,0 +1,59 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Writing game records for completed rounds and replaying them.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use color_eyre::eyre::Result;
+use data::game_record::GameRecord;
+use data::primitive::primitives::PlayerName;
+use data::round_data::RoundData;
+use rules::rounds::{replay, scoring};
+use tracing::info;
+
+use crate::utils;
+
+/// Directory within the data directory where game records are written.
+const RECORDS_FOLDER: &str = "records";
+
+/// Writes a record for a completed round to the records directory, returning
+/// the path of the new file.
+pub fn write(record: &GameRecord) -> Result<PathBuf> {
+    let directory = utils::get_data_dir().join(RECORDS_FOLDER);
+    fs::create_dir_all(&directory)?;
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+    let path = directory.join(format!("round-{timestamp}.json"));
+    fs::write(&path, serde_json::to_string_pretty(record)?)?;
+    info!(?path, "Wrote game record");
+    Ok(path)
+}
+
+/// Replays the game record at `path`, printing each action and the final
+/// scores. Returns an error if any action in the record is illegal.
+pub fn replay(path: &Path) -> Result<()> {
+    let record = serde_json::from_str::<GameRecord>(&fs::read_to_string(path)?)?;
+    let result = replay::replay(&record, |action, _| println!("{action:?}"))?;
+    match result {
+        RoundData::PlayPhase(data) if data.turn.is_none() => {
+            for player in enum_iterator::all::<PlayerName>() {
+                println!("{player} scored {}", scoring::score(&data, player));
+            }
+        }
+        _ => println!("Round is still in progress"),
+    }
+    Ok(())
+}
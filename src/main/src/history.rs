@@ -0,0 +1,73 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Undo & redo of user actions within a round.
+
+use data::game_record::GameRecord;
+use data::round_data::RoundData;
+
+/// State of a round at a point in time, along with its record up to that
+/// point.
+struct Entry {
+    data: RoundData,
+    record: GameRecord,
+}
+
+/// Snapshot-based history of the states preceding each user action.
+///
+/// Agent actions are never recorded on their own, so undoing rewinds past any
+/// agent moves back to the point where the user last acted.
+#[derive(Default)]
+pub struct History {
+    undo: Vec<Entry>,
+    redo: Vec<Entry>,
+}
+
+impl History {
+    /// Records the current state before the user takes an action. Clears any
+    /// actions which could previously have been redone.
+    pub fn push(&mut self, data: &RoundData, record: &GameRecord) {
+        self.undo.push(Entry { data: data.clone(), record: record.clone() });
+        self.redo.clear();
+    }
+
+    /// Restores the state before the user's last action, if any. Increments the
+    /// undo count on the restored record.
+    pub fn undo(&mut self, data: &mut RoundData, record: &mut GameRecord) -> bool {
+        let Some(entry) = self.undo.pop() else {
+            return false;
+        };
+        let undos = record.undos + 1;
+        self.redo.push(Entry {
+            data: std::mem::replace(data, entry.data),
+            record: std::mem::replace(record, entry.record),
+        });
+        record.undos = undos;
+        true
+    }
+
+    /// Restores the state most recently replaced by [Self::undo], if any.
+    pub fn redo(&mut self, data: &mut RoundData, record: &mut GameRecord) -> bool {
+        let Some(entry) = self.redo.pop() else {
+            return false;
+        };
+        let undos = record.undos;
+        self.undo.push(Entry {
+            data: std::mem::replace(data, entry.data),
+            record: std::mem::replace(record, entry.record),
+        });
+        record.undos = undos;
+        true
+    }
+}
@@ -12,15 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::env;
+use std::{env, fs};
 
 use clap::Parser;
-use cli::Cli;
-use color_eyre::eyre::Result;
-use tracing::info;
+use cli::{Cli, Command};
+use color_eyre::eyre::{Result, WrapErr};
+use data::campaign_data::CampaignData;
+use data::pbn;
+use data::primitive::numerics::Intel;
+use rules::rounds::new_round;
+use settings::Settings;
+use tracing::{error, info};
 
 pub mod app;
 pub mod cli;
+pub mod game_records;
+pub mod history;
+pub mod presentation;
+pub mod saved_round;
+pub mod settings;
+pub mod settings_screen;
 pub mod tui;
 pub mod utils;
 
@@ -29,14 +40,37 @@ fn main() -> Result<()> {
     if env::var("DISABLE_PANIC_HANDLER").is_err() {
         utils::initialize_panic_handler()?;
     }
-    Cli::parse();
+    let cli = Cli::parse();
 
     programs::linkme();
 
+    if let Some(Command::Replay { path }) = &cli.command {
+        return game_records::replay(path);
+    }
+
+    let settings = settings::load().unwrap_or_else(|e| {
+        error!(?e, "Unable to load settings, using defaults");
+        Settings::default()
+    });
+
+    // Parse the deal before entering the TUI, so errors are printed normally
+    let board = match &cli.pbn {
+        Some(path) => Some(
+            fs::read_to_string(path)
+                .map_err(color_eyre::Report::from)
+                .and_then(|text| Ok(pbn::parse_boards(&text)?.remove(0)))
+                .wrap_err_with(|| format!("Unable to load deal from {}", path.display()))?,
+        ),
+        None => None,
+    };
+
     let mut tui = tui::enter()?;
     let commit = env!("VERGEN_GIT_SHA");
-    info!(commit, "Starting game");
-    app::run(&mut tui)?;
+    let seed = cli.seed.unwrap_or_else(rand::random);
+    info!(commit, seed, "Starting game");
+    let campaign =
+        CampaignData::new(cli.rounds, Intel(cli.target_intel), new_round::starting_programs());
+    app::run(&mut tui, seed, board, campaign, settings)?;
     tui::exit()?;
     Ok(())
 }
@@ -0,0 +1,126 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Paces play so the user can follow the cards played by AI agents.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use data::game_action::GameAction;
+use data::play_phase_data::PlayedCard;
+use display::rounds::trick_view::TrickPresentation;
+
+use crate::settings::Settings;
+
+/// Time taken for a played card to move into the trick, if animation is on
+const ANIMATION_TIME: Duration = Duration::from_millis(250);
+
+/// Queues AI agent play actions and releases them one at a time.
+///
+/// After each card is played, the next queued action is held back until the
+/// card has finished moving into the trick and the move delay has passed. When
+/// a card completes a trick, the trick stays on screen with its winner
+/// highlighted for the trick pause instead. None of this blocks: the app loop
+/// calls [Self::pop] every frame and keeps handling input in between.
+pub struct Presentation {
+    queue: VecDeque<GameAction>,
+    /// Time before which no queued action is released
+    ready_at: Instant,
+    /// Most recently played card, when it was played, and whether it
+    /// completed a trick
+    last_play: Option<(PlayedCard, Instant, bool)>,
+    move_delay: Duration,
+    trick_pause: Duration,
+    animation_time: Duration,
+}
+
+impl Presentation {
+    pub fn new(settings: &Settings) -> Self {
+        let mut result = Self {
+            queue: VecDeque::new(),
+            ready_at: Instant::now(),
+            last_play: None,
+            move_delay: Duration::ZERO,
+            trick_pause: Duration::ZERO,
+            animation_time: Duration::ZERO,
+        };
+        result.configure(settings);
+        result
+    }
+
+    /// Applies new delays, taking effect from the next card played.
+    pub fn configure(&mut self, settings: &Settings) {
+        self.move_delay = settings.move_delay();
+        self.trick_pause = settings.trick_pause();
+        self.animation_time = if settings.animate { ANIMATION_TIME } else { Duration::ZERO };
+    }
+
+    pub fn push(&mut self, action: GameAction) {
+        self.queue.push_back(action);
+    }
+
+    /// Removes & returns the next queued action, if one is ready to be applied.
+    pub fn pop(&mut self) -> Option<GameAction> {
+        if Instant::now() >= self.ready_at {
+            self.queue.pop_front()
+        } else {
+            None
+        }
+    }
+
+    /// Discards all queued actions and ends any animation, e.g. after undoing
+    /// an action.
+    pub fn clear(&mut self) {
+        self.queue.clear();
+        self.ready_at = Instant::now();
+        self.last_play = None;
+    }
+
+    /// Records that `played` was just played. `completed_trick` should be true
+    /// if it was the final card of a trick.
+    pub fn card_played(&mut self, played: PlayedCard, completed_trick: bool) {
+        let now = Instant::now();
+        let delay = if completed_trick { self.trick_pause } else { self.move_delay };
+        self.ready_at = now + self.animation_time + delay;
+        self.last_play = Some((played, now, completed_trick));
+    }
+
+    /// True while a completed trick is being shown, e.g. so the final trick of
+    /// a round is visible before the results screen.
+    pub fn is_showing_trick(&self) -> bool {
+        matches!(self.last_play, Some((_, _, true))) && Instant::now() < self.ready_at
+    }
+
+    /// Returns the current animation state for the trick display.
+    pub fn trick(&self) -> TrickPresentation {
+        let Some((played, at, completed_trick)) = self.last_play else {
+            return TrickPresentation::default();
+        };
+        let elapsed = at.elapsed();
+        if elapsed < self.animation_time {
+            TrickPresentation {
+                moving: Some((
+                    played.card,
+                    elapsed.as_secs_f64() / self.animation_time.as_secs_f64(),
+                )),
+                show_winner: false,
+            }
+        } else {
+            TrickPresentation {
+                moving: None,
+                show_winner: completed_trick && self.is_showing_trick(),
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Saving and restoring an in-progress round between launches.
+
+use std::fs;
+use std::path::PathBuf;
+
+use color_eyre::eyre::Result;
+use data::campaign_data::CampaignData;
+use data::game_record::GameRecord;
+use data::round_data::RoundData;
+use data::snapshot::RoundSnapshot;
+use rules::rounds::snapshots;
+use serde::{Deserialize, Serialize};
+use tracing::{error, info};
+
+use crate::utils;
+
+const SAVE_FILE: &str = "saved_round.json";
+
+/// Contents of the save file: the current state of the round plus the record
+/// of how it got there, so the record can still be written once the resumed
+/// round finishes, along with the campaign the round belongs to.
+#[derive(Serialize, Deserialize)]
+struct SavedRound {
+    round: RoundSnapshot,
+    record: GameRecord,
+    campaign: CampaignData,
+}
+
+pub fn path() -> PathBuf {
+    utils::get_data_dir().join(SAVE_FILE)
+}
+
+/// Returns true if a saved round exists.
+pub fn exists() -> bool {
+    path().exists()
+}
+
+/// Writes a snapshot of the provided round, its record and its campaign to the
+/// save file.
+pub fn save(data: &RoundData, record: &GameRecord, campaign: &CampaignData) -> Result<()> {
+    let path = path();
+    let saved =
+        SavedRound { round: data.into(), record: record.clone(), campaign: campaign.clone() };
+    fs::write(&path, serde_json::to_string(&saved)?)?;
+    info!(?path, "Saved round");
+    Ok(())
+}
+
+/// Loads the round in the save file along with its record and campaign.
+///
+/// Returns None if no round has been saved or the save file cannot be read.
+pub fn load() -> Option<(RoundData, GameRecord, CampaignData)> {
+    let path = path();
+    let result = fs::read_to_string(&path)
+        .map_err(color_eyre::Report::from)
+        .and_then(|json| Ok(serde_json::from_str::<SavedRound>(&json)?));
+    match result {
+        Ok(saved) => {
+            info!(?path, "Loaded saved round");
+            Some((snapshots::restore(saved.round), saved.record, saved.campaign))
+        }
+        Err(e) => {
+            error!(?path, ?e, "Unable to load saved round");
+            None
+        }
+    }
+}
+
+/// Removes the save file, if any.
+pub fn delete() -> Result<()> {
+    if exists() {
+        fs::remove_file(path())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use data::delegate_data::{HasPrograms, ProgramId};
+    use data::play_phase_data::{PlayPhaseAction, PlayPhaseData};
+    use data::primitive::primitives::{PlayerName, Suit};
+    use data::program_name::ProgramName;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use rules::play_phase::{play_phase_actions, play_phase_queries};
+    use rules::rounds::new_round;
+
+    use super::*;
+
+    /// Saves `data` in the save file format and restores it.
+    fn round_trip(data: &RoundData) -> RoundData {
+        let json = serde_json::to_string(&RoundSnapshot::from(data)).expect("Serialization failed");
+        snapshots::restore(serde_json::from_str(&json).expect("Deserialization failed"))
+    }
+
+    /// Plays the first legal card for each player until the round ends,
+    /// returning the winner of each trick.
+    fn play_out(data: &mut PlayPhaseData) -> Vec<PlayerName> {
+        while let Some(player) = data.turn {
+            let card = play_phase_queries::legal_actions(data, player)
+                .find(|action| matches!(action, PlayPhaseAction::PlayCard(_)))
+                .expect("No legal cards");
+            play_phase_actions::handle_action(data, player, card);
+        }
+        data.completed_tricks.iter().map(|completed| completed.winner).collect()
+    }
+
+    #[test]
+    fn restores_contract_phase() {
+        programs::linkme();
+        let data = new_round::create(&mut StdRng::seed_from_u64(1));
+        let (RoundData::ContractPhase(original), RoundData::ContractPhase(restored)) =
+            (&data, round_trip(&data))
+        else {
+            panic!("Expected ContractPhase");
+        };
+        assert_eq!(restored.trump, original.trump);
+        assert_eq!(format!("{:?}", restored.contracts), format!("{:?}", original.contracts));
+        assert_eq!(restored.hands.to_string(), original.hands.to_string());
+        assert_eq!(restored.step, original.step);
+        assert_eq!(restored.leader, original.leader);
+        assert_eq!(restored.programs.all_programs, original.programs.all_programs);
+    }
+
+    #[test]
+    fn restores_play_phase_with_program_state() {
+        programs::linkme();
+        let mut original = new_round::create_play_phase(&mut StdRng::seed_from_u64(1), 3);
+        let user = PlayerName::User;
+        for name in [ProgramName::Obsidian, ProgramName::Starfall] {
+            let action = PlayPhaseAction::ActivateProgram(ProgramId::new(name, user));
+            assert!(play_phase_queries::legal_actions(&original, user).any(|a| a == action));
+            play_phase_actions::handle_action(&mut original, user, action);
+        }
+        for player in [user, user.next()] {
+            let card = play_phase_queries::legal_actions(&original, player)
+                .find(|action| matches!(action, PlayPhaseAction::PlayCard(_)))
+                .expect("No legal cards");
+            play_phase_actions::handle_action(&mut original, player, card);
+        }
+
+        let RoundData::PlayPhase(mut restored) =
+            round_trip(&RoundData::PlayPhase(original.clone()))
+        else {
+            panic!("Expected PlayPhase");
+        };
+        assert_eq!(restored.trump, Some(Suit::Spades));
+        assert_eq!(
+            format!("{:?}", restored.current_trick),
+            format!("{:?}", original.current_trick)
+        );
+        assert_eq!(restored.turn, original.turn);
+        assert_eq!(restored.leader, original.leader);
+        assert_eq!(restored.hands.to_string(), original.hands.to_string());
+        assert_eq!(restored.programs.all_programs, original.programs.all_programs);
+        assert!(restored.programs.program_state == original.programs.program_state);
+        assert_eq!(restored.programs.activated, original.programs.activated);
+
+        // Rebuilt delegates must report the same activation states and apply
+        // the same effects, e.g. Starfall winning the current trick
+        for (&owner, names) in &original.programs.all_programs {
+            for &name in names {
+                let id = ProgramId::new(name, owner);
+                assert_eq!(restored.activation_state(id), original.activation_state(id), "{id:?}");
+            }
+        }
+        let winners = play_out(&mut original);
+        assert_eq!(winners[0], user);
+        assert_eq!(play_out(&mut restored), winners);
+    }
+}
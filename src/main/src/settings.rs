@@ -0,0 +1,150 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! User settings, loaded from a file in the config directory with overrides
+//! from environment variables.
+
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use ai::game::agents::AgentName;
+use color_eyre::eyre::Result;
+use config::{Config, Environment, File};
+use data::design::theme::{Theme, ThemeName};
+use display::core::key_bindings::KeyBindings;
+use enum_iterator::Sequence;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::utils;
+
+const SETTINGS_FILE: &str = "settings.toml";
+
+/// Controls how long AI agents may think about each move
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Sequence, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn think_time(&self) -> Duration {
+        match self {
+            Difficulty::Easy => Duration::from_millis(250),
+            Difficulty::Normal => Duration::from_secs(1),
+            Difficulty::Hard => Duration::from_secs(3),
+        }
+    }
+}
+
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub difficulty: Difficulty,
+    /// Agent which selects moves for AI players
+    pub agent: AgentName,
+    /// Minimum time in milliseconds between each card played and the next AI
+    /// move, after any card animation.
+    ///
+    /// This is measured from when the card is played, so it overlaps with the
+    /// AI's think time instead of adding to it. Older versions measured it from
+    /// when the AI began searching.
+    pub move_delay_ms: u64,
+    /// Time in milliseconds to show each completed trick before play continues
+    pub trick_pause_ms: u64,
+    /// Animate cards moving into the trick
+    pub animate: bool,
+    pub theme: ThemeName,
+    /// Use colorblind-friendly suit colors & glyphs
+    pub colorblind: bool,
+    pub key_bindings: KeyBindings,
+}
+
+impl Settings {
+    pub fn move_delay(&self) -> Duration {
+        Duration::from_millis(self.move_delay_ms)
+    }
+
+    pub fn trick_pause(&self) -> Duration {
+        Duration::from_millis(self.trick_pause_ms)
+    }
+
+    pub fn theme(&self) -> Theme {
+        Theme { name: self.theme, colorblind: self.colorblind }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            difficulty: Difficulty::Normal,
+            agent: AgentName::Uct1InformationSet,
+            move_delay_ms: 0,
+            trick_pause_ms: 1200,
+            animate: true,
+            theme: ThemeName::Default,
+            colorblind: false,
+            key_bindings: KeyBindings::default(),
+        }
+    }
+}
+
+pub fn path() -> PathBuf {
+    utils::get_config_dir().join(SETTINGS_FILE)
+}
+
+/// Loads settings from the settings file, if it exists, and then applies
+/// overrides from environment variables.
+///
+/// Variables use the project prefix, with `__` separating nested keys, e.g.
+/// `DOTDQ_DIFFICULTY=hard` or `DOTDQ_KEY_BINDINGS__UNDO=z`.
+pub fn load() -> Result<Settings> {
+    let path = path();
+    let mut settings = Config::builder()
+        .add_source(File::from(path.clone()).required(false))
+        .add_source(
+            Environment::with_prefix(&utils::PROJECT_NAME).prefix_separator("_").separator("__"),
+        )
+        .build()?
+        .try_deserialize::<Settings>()?;
+    if let Err(e) = settings.key_bindings.validate() {
+        warn!(?e, "Invalid key bindings, using defaults");
+        settings.key_bindings = KeyBindings::default();
+    }
+    info!(?path, ?settings, "Loaded settings");
+    Ok(settings)
+}
+
+/// Writes settings to the settings file.
+pub fn save(settings: &Settings) -> Result<()> {
+    let path = path();
+    fs::create_dir_all(utils::get_config_dir())?;
+    fs::write(&path, toml::to_string_pretty(settings)?)?;
+    info!(?path, "Saved settings");
+    Ok(())
+}
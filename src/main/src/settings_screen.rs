@@ -0,0 +1,257 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Modal screen for editing [Settings] during a game.
+
+use std::fmt::{self, Display, Formatter};
+
+use ai::game::agents::AgentName;
+use clap::ValueEnum;
+use crossterm::event::{Event, KeyCode, KeyEventKind};
+use data::design::colors;
+use display::core::layout;
+use enum_iterator::{next_cycle, previous_cycle, Sequence};
+use ratatui::layout::Size;
+use ratatui::prelude::*;
+use ratatui::symbols::border;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::settings::Settings;
+
+const DELAY_STEP_MS: u64 = 100;
+const MAX_DELAY_MS: u64 = 5000;
+
+/// A row of the settings screen
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Sequence)]
+enum Row {
+    Difficulty,
+    Agent,
+    MoveDelay,
+    TrickPause,
+    Animate,
+    Theme,
+    Colorblind,
+    QuitKey,
+    UndoKey,
+    RedoKey,
+    SettingsKey,
+}
+
+impl Row {
+    fn key(self, settings: &mut Settings) -> Option<&mut char> {
+        let bindings = &mut settings.key_bindings;
+        match self {
+            Row::QuitKey => Some(&mut bindings.quit),
+            Row::UndoKey => Some(&mut bindings.undo),
+            Row::RedoKey => Some(&mut bindings.redo),
+            Row::SettingsKey => Some(&mut bindings.settings),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Row {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Row::Difficulty => "Difficulty",
+            Row::Agent => "AI Agent",
+            Row::MoveDelay => "Move Delay",
+            Row::TrickPause => "Trick Pause",
+            Row::Animate => "Animate",
+            Row::Theme => "Theme",
+            Row::Colorblind => "Colorblind",
+            Row::QuitKey => "Quit Key",
+            Row::UndoKey => "Undo Key",
+            Row::RedoKey => "Redo Key",
+            Row::SettingsKey => "Settings Key",
+        })
+    }
+}
+
+/// Result of closing the settings screen
+#[derive(Debug, Clone)]
+pub enum Outcome {
+    Save(Settings),
+    Cancel,
+}
+
+/// Edits a draft copy of the current settings.
+///
+/// ```text
+/// Up, Down      select a setting
+/// Left, Right   change the selected value
+/// Enter         rebind the selected key, then press the new key
+/// s             save & close
+/// Esc           discard & close
+/// ```
+pub struct SettingsScreen {
+    draft: Settings,
+    selected: Row,
+    /// True while waiting for a new key for the selected key binding
+    capturing: bool,
+    /// Explains why the last key typed while capturing was rejected
+    error: Option<String>,
+}
+
+impl SettingsScreen {
+    pub fn new(settings: &Settings) -> Self {
+        Self { draft: settings.clone(), selected: Row::Difficulty, capturing: false, error: None }
+    }
+
+    /// Updates the screen for a terminal event. Returns an [Outcome] if the
+    /// screen should be closed.
+    pub fn handle_event(&mut self, event: &Event) -> Option<Outcome> {
+        let Event::Key(key) = event else {
+            return None;
+        };
+        if key.kind != KeyEventKind::Press {
+            return None;
+        }
+
+        self.error = None;
+        if self.capturing {
+            self.capturing = false;
+            if let KeyCode::Char(c) = key.code {
+                self.rebind(c.to_ascii_lowercase());
+            }
+            return None;
+        }
+
+        match key.code {
+            KeyCode::Up => self.selected = previous_cycle(&self.selected),
+            KeyCode::Down | KeyCode::Tab => self.selected = next_cycle(&self.selected),
+            KeyCode::Left => self.change(false),
+            KeyCode::Right => self.change(true),
+            KeyCode::Enter if self.selected.key(&mut self.draft).is_some() => self.capturing = true,
+            KeyCode::Char('s') => return Some(Outcome::Save(self.draft.clone())),
+            KeyCode::Esc => return Some(Outcome::Cancel),
+            _ => {}
+        }
+        None
+    }
+
+    /// Binds `c` to the selected command, unless that would make the key
+    /// bindings invalid.
+    fn rebind(&mut self, c: char) {
+        let mut draft = self.draft.clone();
+        if let Some(binding) = self.selected.key(&mut draft) {
+            *binding = c;
+        }
+        match draft.key_bindings.validate() {
+            Ok(()) => self.draft = draft,
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    fn change(&mut self, forward: bool) {
+        let draft = &mut self.draft;
+        match self.selected {
+            Row::Difficulty => {
+                draft.difficulty = cycle(&draft.difficulty, forward);
+            }
+            Row::Agent => {
+                let agents = AgentName::value_variants();
+                let index = agents.iter().position(|a| *a == draft.agent).unwrap_or_default();
+                let next = if forward {
+                    (index + 1) % agents.len()
+                } else {
+                    (index + agents.len() - 1) % agents.len()
+                };
+                draft.agent = agents[next];
+            }
+            Row::MoveDelay => {
+                draft.move_delay_ms = step_delay(draft.move_delay_ms, forward);
+            }
+            Row::TrickPause => {
+                draft.trick_pause_ms = step_delay(draft.trick_pause_ms, forward);
+            }
+            Row::Animate => {
+                draft.animate = !draft.animate;
+            }
+            Row::Theme => {
+                draft.theme = cycle(&draft.theme, forward);
+            }
+            Row::Colorblind => {
+                draft.colorblind = !draft.colorblind;
+            }
+            _ => {}
+        }
+    }
+
+    fn value(&self, row: Row) -> String {
+        let bindings = &self.draft.key_bindings;
+        match row {
+            Row::Difficulty => self.draft.difficulty.to_string(),
+            Row::Agent => format!("{:?}", self.draft.agent),
+            Row::MoveDelay => format!("{}ms", self.draft.move_delay_ms),
+            Row::TrickPause => format!("{}ms", self.draft.trick_pause_ms),
+            Row::Animate => on_off(self.draft.animate),
+            Row::Theme => self.draft.theme.to_string(),
+            Row::Colorblind => on_off(self.draft.colorblind),
+            _ if self.capturing && row == self.selected => "press a key".to_string(),
+            Row::QuitKey => bindings.quit.to_string(),
+            Row::UndoKey => bindings.undo.to_string(),
+            Row::RedoKey => bindings.redo.to_string(),
+            Row::SettingsKey => bindings.settings.to_string(),
+        }
+    }
+}
+
+impl Widget for &SettingsScreen {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let center = layout::centered_rect(Size { width: 50, height: 17 }, area);
+        Clear.render(center, buf);
+        let mut lines = enum_iterator::all::<Row>()
+            .map(|row| {
+                let color = if row == self.selected { colors::yellow() } else { colors::white() };
+                Line::from(vec![
+                    format!("{:<14}", row.to_string()).fg(colors::light_gray()),
+                    format!("◀ {} ▶", self.value(row)).fg(color),
+                ])
+            })
+            .collect::<Vec<_>>();
+        lines.push(Line::from(self.error.clone().unwrap_or_default().fg(colors::red())));
+        lines.push(Line::from("↑↓ select  ←→ change  Enter rebind".fg(colors::light_gray())));
+        lines.push(Line::from("s save  Esc cancel".fg(colors::light_gray())));
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title("Settings")
+                    .borders(Borders::ALL)
+                    .border_set(border::PLAIN)
+                    .border_style(colors::white()),
+            )
+            .render(center, buf);
+    }
+}
+
+fn cycle<T: Sequence>(value: &T, forward: bool) -> T {
+    if forward {
+        next_cycle(value)
+    } else {
+        previous_cycle(value)
+    }
+}
+
+fn step_delay(delay_ms: u64, forward: bool) -> u64 {
+    if forward {
+        (delay_ms + DELAY_STEP_MS).min(MAX_DELAY_MS)
+    } else {
+        delay_ms.saturating_sub(DELAY_STEP_MS)
+    }
+}
+
+fn on_off(value: bool) -> String {
+    if value { "On" } else { "Off" }.to_string()
+}
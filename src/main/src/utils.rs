@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std::panic;
-use std::panic::PanicInfo;
+use std::panic::PanicHookInfo;
 use std::path::PathBuf;
 
 use color_eyre::config::{HookBuilder, PanicHook};
@@ -33,6 +33,8 @@ lazy_static! {
     pub static ref PROJECT_NAME: String = env!("CARGO_CRATE_NAME").to_uppercase().to_string();
     pub static ref DATA_FOLDER: Option<PathBuf> =
         std::env::var(format!("{}_DATA", PROJECT_NAME.clone())).ok().map(PathBuf::from);
+    pub static ref CONFIG_FOLDER: Option<PathBuf> =
+        std::env::var(format!("{}_CONFIG", PROJECT_NAME.clone())).ok().map(PathBuf::from);
     pub static ref LOG_ENV: String = format!("{}_LOGLEVEL", PROJECT_NAME.clone());
     pub static ref LOG_FILE: String = format!("{}.log", env!("CARGO_PKG_NAME"));
 }
@@ -64,7 +66,7 @@ pub fn initialize_panic_handler() -> Result<()> {
     Ok(())
 }
 
-fn on_panic(panic_hook: &PanicHook, panic_info: &PanicInfo) {
+fn on_panic(panic_hook: &PanicHook, panic_info: &PanicHookInfo) {
     if let Err(r) = tui::exit() {
         error!("Unable to exit Terminal: {:?}", r);
     }
@@ -113,6 +115,17 @@ pub fn get_data_dir() -> PathBuf {
     directory
 }
 
+pub fn get_config_dir() -> PathBuf {
+    let directory = if let Some(s) = CONFIG_FOLDER.clone() {
+        s
+    } else if let Some(proj_dirs) = project_directory() {
+        proj_dirs.config_local_dir().to_path_buf()
+    } else {
+        PathBuf::from(".").join(".config")
+    };
+    directory
+}
+
 pub fn initialize_logging() -> Result<()> {
     let directory = get_data_dir();
     std::fs::create_dir_all(directory.clone())?;
@@ -142,6 +155,7 @@ pub fn version() -> String {
     let log_file = get_data_dir().join(LOG_FILE.clone());
     let log_file_string = log_file.display();
     let data_dir_path = get_data_dir().display().to_string();
+    let config_dir_path = get_config_dir().display().to_string();
 
     format!(
         "\
@@ -150,7 +164,8 @@ pub fn version() -> String {
 Built: {build_date}
 Commit: {sha}
 Log file: {log_file_string}
-Data directory: {data_dir_path}"
+Data directory: {data_dir_path}
+Config directory: {config_dir_path}"
     )
 }
 
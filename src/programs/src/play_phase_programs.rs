@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use data::design::symbols;
+use data::primitive::numerics::Intel;
 use data::primitive::primitives::Suit;
 use data::program_data::{ProgramDefinition, PROGRAMS};
 use data::program_name::ProgramName;
@@ -26,6 +27,7 @@ pub fn starfall() -> ProgramDefinition {
     ProgramDefinition::new()
         .name(ProgramName::Starfall)
         .text(vec![Span::raw("↳Round: Win this trick.")])
+        .cost(Intel(150))
         .play_phase(|on| {
             activation::activate_for_trick::<DuringTurn>(on);
             on.trick_winner.queried(|_, context, &number, current| {
@@ -44,6 +46,7 @@ pub fn obsidian() -> ProgramDefinition {
     ProgramDefinition::new()
         .name(ProgramName::Obsidian)
         .text(vec![Span::raw("↳Round: Change the trump suit to "), symbols::suit(Suit::Spades)])
+        .cost(Intel(60))
         .play_phase(|on| {
             activation::can_activate::<DuringTurn>(on);
             on.activated.this(|data, _| {
@@ -58,6 +61,7 @@ pub fn eviction() -> ProgramDefinition {
     ProgramDefinition::new()
         .name(ProgramName::Eviction)
         .text(vec![Span::raw("↳Round: You do not need to follow suit this trick.")])
+        .cost(Intel(80))
         .play_phase(|on| {
             activation::activate_for_trick::<DuringTurn>(on);
             on.must_follow_suit.queried(|_, context, p, current| {
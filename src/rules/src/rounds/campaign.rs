@@ -0,0 +1,104 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use data::campaign_data::{CampaignData, RoundResult};
+use data::play_phase_data::PlayPhaseData;
+use data::primitive::numerics::Intel;
+use data::primitive::primitives::PlayerName;
+use data::program_name::ProgramName;
+use data::round_data::RoundData;
+use rand::prelude::SliceRandom;
+use rand::Rng;
+use tracing::info;
+
+use crate::program::programs;
+use crate::rounds::{new_round, scoring, tricks};
+
+/// Adds the outcome of a completed round to the campaign's results, along
+/// with the number of times the user undid an action during it.
+pub fn record_result(campaign: &mut CampaignData, data: &PlayPhaseData, undos: usize) {
+    assert!(data.turn.is_none(), "Round is not complete");
+    let players = enum_iterator::all::<PlayerName>();
+    let result = RoundResult {
+        contracts: data.contracts,
+        tricks: players.clone().map(|p| (p, tricks::won(data, p))).collect(),
+        intel: players.map(|p| (p, scoring::score(data, p))).collect(),
+        undos,
+    };
+    info!(?result, "Round complete");
+    campaign.results.push(result);
+}
+
+/// Returns true if the campaign has reached its round limit or any player has
+/// reached the target [data::primitive::numerics::Intel] total.
+pub fn is_over(campaign: &CampaignData) -> bool {
+    campaign.results.len() >= campaign.max_rounds
+        || enum_iterator::all::<PlayerName>().any(|p| campaign.total(p) >= campaign.target)
+}
+
+/// Deals a round of the campaign using the program [loadouts] for each player.
+pub fn deal(campaign: &CampaignData, rng: &mut impl Rng) -> RoundData {
+    let programs = loadouts(campaign, rng);
+    new_round::create_with(rng, campaign.leader, programs)
+}
+
+/// Programs each player owns for the next round of the campaign.
+///
+/// Players keep the programs they have purchased so far. Each AI agent is also
+/// loaned one additional program, chosen at random, for the round.
+pub fn loadouts(
+    campaign: &CampaignData,
+    rng: &mut impl Rng,
+) -> HashMap<PlayerName, Vec<ProgramName>> {
+    let mut result = campaign.programs.clone();
+    for agent in enum_iterator::all::<PlayerName>().filter(PlayerName::is_agent) {
+        let owned = result.entry(agent).or_default();
+        let loaned = enum_iterator::all::<ProgramName>()
+            .filter(|p| !owned.contains(p))
+            .collect::<Vec<_>>()
+            .choose(rng)
+            .copied();
+        owned.extend(loaned);
+    }
+    result
+}
+
+/// Deals the next round of the campaign. The lead for the first trick passes
+/// to the next player in turn order.
+pub fn next_round(campaign: &mut CampaignData, rng: &mut impl Rng) -> RoundData {
+    assert!(!is_over(campaign), "Campaign is over");
+    campaign.leader = campaign.leader.next();
+    deal(campaign, rng)
+}
+
+/// Returns true if the [PlayerName] player can currently purchase the
+/// [ProgramName] program: they must not already own it and must have enough
+/// available Intel.
+pub fn can_buy(campaign: &CampaignData, player: PlayerName, program: ProgramName) -> bool {
+    !is_over(campaign)
+        && !campaign.owns(player, program)
+        && campaign.available(player) >= programs::get(program).cost
+}
+
+/// Purchases the [ProgramName] program for the [PlayerName] player, adding it
+/// to the programs they own in subsequent rounds.
+pub fn buy(campaign: &mut CampaignData, player: PlayerName, program: ProgramName) {
+    assert!(can_buy(campaign, player, program), "Cannot buy {program}");
+    let cost = programs::get(program).cost;
+    *campaign.spent.entry(player).or_insert(Intel(0)) += cost;
+    campaign.programs.entry(player).or_default().push(program);
+    info!(?player, ?program, ?cost, "Purchased program");
+}
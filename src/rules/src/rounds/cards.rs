@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use data::delegate_data::PlayerTrickNumber;
-use data::play_phase_data::{CompletedTrick, PlayPhaseData, PlayedCard};
+use data::play_phase_data::{CompletedTrick, PlayPhaseData, PlayedCard, TrickNumber};
 use data::primitive::primitives::{Card, PlayerName};
 
 use crate::play_phase::play_phase_queries;
@@ -40,15 +40,21 @@ pub fn can_play(data: &PlayPhaseData, player: PlayerName, card: Card) -> bool {
         true
     };
 
-    let must_follow_suit = data.programs.current_delegates.must_follow_suit.run_query(
-        data,
-        &PlayerTrickNumber::new(player, tricks::current_number(data)),
-        true,
-    );
-
     data.is_turn(player)
         && data.hands.hand(player).contains(card)
-        && (data.current_trick.cards.len() == 4 || follows_suit || !must_follow_suit)
+        && (data.current_trick.cards.len() == 4
+            || follows_suit
+            || !must_follow_suit(data, player, tricks::current_number(data)))
+}
+
+/// Returns true if the [PlayerName] player is required to follow suit during
+/// the trick with the provided [TrickNumber].
+pub fn must_follow_suit(data: &PlayPhaseData, player: PlayerName, number: TrickNumber) -> bool {
+    data.programs.current_delegates.must_follow_suit.run_query(
+        data,
+        &PlayerTrickNumber::new(player, number),
+        true,
+    )
 }
 
 /// Returns the [PlayerName] to next play a card during a round.
@@ -62,7 +68,7 @@ fn next_to_play(data: &PlayPhaseData) -> Option<PlayerName> {
             if let Some(last) = data.completed_tricks.last() {
                 last.winner
             } else {
-                PlayerName::User
+                data.leader
             }
         }
         1..=3 => data.current_trick.cards.last().unwrap().played_by.next(),
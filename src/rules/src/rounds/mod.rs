@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod campaign;
 pub mod cards;
 pub mod new_round;
+pub mod replay;
 pub mod scoring;
+pub mod snapshots;
 pub mod start_play_phase;
 pub mod tricks;
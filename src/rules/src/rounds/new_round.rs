@@ -29,6 +29,16 @@ use rand::Rng;
 use crate::rounds::start_play_phase;
 
 pub fn create(rng: &mut impl Rng) -> RoundData {
+    create_with(rng, PlayerName::User, starting_programs())
+}
+
+/// Deals a new round in the contract phase where the `leader` player will lead
+/// the first trick and each player owns the programs in `all_programs`.
+pub fn create_with(
+    rng: &mut impl Rng,
+    leader: PlayerName,
+    all_programs: HashMap<PlayerName, Vec<ProgramName>>,
+) -> RoundData {
     let mut cards = Vec::new();
     for suit in enum_iterator::all::<Suit>() {
         for rank in enum_iterator::all::<Rank>() {
@@ -47,26 +57,64 @@ pub fn create(rng: &mut impl Rng) -> RoundData {
         *[None, Some(Suit::Clubs), Some(Suit::Diamonds), Some(Suit::Hearts), Some(Suit::Spades)]
             .choose(rng)
             .expect("Empty slice");
+    deal(Hands::new(north, east, south, west), trump, leader, all_programs)
+}
+
+/// Creates a new round in the contract phase with the provided hands and trump
+/// suit.
+pub fn from_deal(hands: Hands, trump: Option<Suit>) -> RoundData {
+    deal(hands, trump, PlayerName::User, starting_programs())
+}
+
+/// Creates a new round in the contract phase with the provided hands, trump
+/// suit, first trick leader and programs for each player.
+pub fn deal(
+    hands: Hands,
+    trump: Option<Suit>,
+    leader: PlayerName,
+    all_programs: HashMap<PlayerName, Vec<ProgramName>>,
+) -> RoundData {
     RoundData::ContractPhase(ContractPhaseData {
         trump,
         contracts: Contracts::default(),
-        hands: Hands::new(north, east, south, west),
+        hands,
         step: ContractPhaseStep::AwaitingUserContact,
+        leader,
         programs: ProgramData {
             current_delegates: ContractPhaseDelegates::default(),
             program_state: HashMap::default(),
-            all_programs: HashMap::from([(PlayerName::User, vec![
-                ProgramName::Starfall,
-                ProgramName::Obsidian,
-                ProgramName::Eviction,
-            ])]),
+            all_programs,
             activated: HashSet::new(),
         },
     })
 }
 
+/// Programs owned by the user at the start of a campaign, or in a standalone
+/// round outside of a campaign.
+pub fn starting_programs() -> HashMap<PlayerName, Vec<ProgramName>> {
+    HashMap::from([(PlayerName::User, vec![
+        ProgramName::Starfall,
+        ProgramName::Obsidian,
+        ProgramName::Eviction,
+    ])])
+}
+
 pub fn create_play_phase(rng: &mut impl Rng, contract: ContractNumber) -> PlayPhaseData {
-    let RoundData::ContractPhase(mut data) = create(rng) else {
+    start_with_contracts(create(rng), contract)
+}
+
+/// Creates a new round in the play phase with the provided hands and trump
+/// suit, where every player has the same `contract`.
+pub fn play_phase_from_deal(
+    hands: Hands,
+    trump: Option<Suit>,
+    contract: ContractNumber,
+) -> PlayPhaseData {
+    start_with_contracts(from_deal(hands, trump), contract)
+}
+
+fn start_with_contracts(round: RoundData, contract: ContractNumber) -> PlayPhaseData {
+    let RoundData::ContractPhase(mut data) = round else {
         panic!("Expected ContractPhase");
     };
     *data.contracts.contract_number_mut(PlayerName::User) = contract;
@@ -0,0 +1,93 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use data::game_record::{GameRecord, RecordedAction};
+use data::round_data::RoundData;
+
+use crate::contract_phase::{contract_phase_actions, contract_phase_queries};
+use crate::play_phase::{play_phase_actions, play_phase_queries};
+use crate::rounds::snapshots;
+
+/// Error returned when a [GameRecord] contains an action which was not legal
+/// at the point it was recorded.
+#[derive(Debug)]
+pub struct IllegalAction {
+    /// Position of the action within [GameRecord::actions].
+    pub index: usize,
+    pub action: RecordedAction,
+}
+
+impl Display for IllegalAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Illegal action {:?} at index {}", self.action, self.index)
+    }
+}
+
+impl Error for IllegalAction {}
+
+/// Rebuilds the round described by a [GameRecord], checking that each action
+/// is legal before applying it.
+///
+/// `on_action` is invoked with each action and the state that results from
+/// applying it. Returns the final state of the round.
+pub fn replay(
+    record: &GameRecord,
+    mut on_action: impl FnMut(RecordedAction, &RoundData),
+) -> Result<RoundData, IllegalAction> {
+    let mut data = snapshots::restore(record.initial.clone());
+    for (index, &action) in record.actions.iter().enumerate() {
+        if !is_legal(&data, action) {
+            return Err(IllegalAction { index, action });
+        }
+        apply(&mut data, action);
+        on_action(action, &data);
+    }
+    Ok(data)
+}
+
+/// Returns true if `action` can currently be taken in `data`.
+pub fn is_legal(data: &RoundData, action: RecordedAction) -> bool {
+    match (data, action) {
+        (RoundData::ContractPhase(contract_data), RecordedAction::Contract(player, a)) => {
+            contract_phase_queries::can_perform_action(contract_data, player, a)
+        }
+        (RoundData::PlayPhase(play_data), RecordedAction::Play(player, a)) => {
+            play_data.turn == Some(player)
+                && play_phase_queries::can_perform_action(play_data, player, a)
+        }
+        _ => false,
+    }
+}
+
+/// Applies a legal `action` to `data`.
+///
+/// Panics if the action is not legal, per [is_legal].
+pub fn apply(data: &mut RoundData, action: RecordedAction) {
+    let result = match (&mut *data, action) {
+        (RoundData::ContractPhase(contract_data), RecordedAction::Contract(player, a)) => {
+            contract_phase_actions::handle_action(contract_data, player, a)
+        }
+        (RoundData::PlayPhase(play_data), RecordedAction::Play(player, a)) => {
+            play_phase_actions::handle_action(play_data, player, a);
+            None
+        }
+        (_, action) => panic!("Action {action:?} not valid for current phase"),
+    };
+    if let Some(result) = result {
+        *data = result;
+    }
+}
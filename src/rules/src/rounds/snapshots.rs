@@ -0,0 +1,59 @@
+// Copyright © Dungeon of the Diamond Queen 2024-present
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+
+use data::contract_phase_data::ContractPhaseData;
+use data::delegate_data::ContractPhaseDelegates;
+use data::play_phase_data::PlayPhaseData;
+use data::program_data::ProgramData;
+use data::round_data::RoundData;
+use data::snapshot::RoundSnapshot;
+
+use crate::rounds::start_play_phase;
+
+/// Rebuilds a [RoundData] from a [RoundSnapshot], registering program
+/// delegates in the same way as when the round was originally started.
+pub fn restore(snapshot: RoundSnapshot) -> RoundData {
+    match snapshot {
+        RoundSnapshot::ContractPhase(s) => RoundData::ContractPhase(ContractPhaseData {
+            trump: s.trump,
+            contracts: s.contracts,
+            hands: s.hands,
+            step: s.step,
+            leader: s.leader,
+            programs: ProgramData {
+                current_delegates: ContractPhaseDelegates::default(),
+                program_state: HashMap::new(),
+                all_programs: s.all_programs,
+                activated: HashSet::new(),
+            },
+        }),
+        RoundSnapshot::PlayPhase(s) => RoundData::PlayPhase(PlayPhaseData {
+            current_trick: s.current_trick,
+            turn: s.turn,
+            leader: s.leader,
+            completed_tricks: s.completed_tricks,
+            trump: s.trump,
+            contracts: s.contracts,
+            hands: s.hands,
+            programs: ProgramData {
+                current_delegates: start_play_phase::delegates(&s.all_programs),
+                program_state: s.program_state.into_iter().collect(),
+                all_programs: s.all_programs,
+                activated: s.activated.into_iter().collect(),
+            },
+        }),
+    }
+}
@@ -19,34 +19,40 @@ use data::delegate_data::{PlayPhaseDelegates, ProgramId};
 use data::play_phase_data::{PlayPhaseData, Trick};
 use data::primitive::primitives::PlayerName;
 use data::program_data::ProgramData;
+use data::program_name::ProgramName;
 
 use crate::program::programs;
 
 pub fn run(data: ContractPhaseData) -> PlayPhaseData {
-    let mut delegates = PlayPhaseDelegates::default();
-    for player in enum_iterator::all::<PlayerName>() {
-        for program in data.programs.all_programs.get(&player).unwrap_or(&vec![]) {
-            let definition = programs::get(*program);
-            if let Some(play_phase) = definition.play_phase {
-                let id = ProgramId::new(*program, player);
-                play_phase(&mut delegates);
-                delegates.set_current_id(id);
-            }
-        }
-    }
-
     PlayPhaseData {
         current_trick: Trick::default(),
-        turn: Some(PlayerName::User),
+        turn: Some(data.leader),
+        leader: data.leader,
         completed_tricks: vec![],
         trump: data.trump,
         contracts: data.contracts,
         hands: data.hands,
         programs: ProgramData {
-            current_delegates: delegates,
+            current_delegates: delegates(&data.programs.all_programs),
             program_state: HashMap::new(),
             all_programs: data.programs.all_programs,
             activated: HashSet::new(),
         },
     }
 }
+
+/// Registers the play phase delegates for each program owned by each player.
+pub fn delegates(all_programs: &HashMap<PlayerName, Vec<ProgramName>>) -> PlayPhaseDelegates {
+    let mut delegates = PlayPhaseDelegates::default();
+    for player in enum_iterator::all::<PlayerName>() {
+        for program in all_programs.get(&player).unwrap_or(&vec![]) {
+            let definition = programs::get(*program);
+            if let Some(play_phase) = definition.play_phase {
+                let id = ProgramId::new(*program, player);
+                play_phase(&mut delegates);
+                delegates.set_current_id(id);
+            }
+        }
+    }
+    delegates
+}
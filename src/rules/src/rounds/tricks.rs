@@ -23,7 +23,7 @@ pub fn has_lead(data: &PlayPhaseData, player_name: PlayerName) -> bool {
         return false;
     }
 
-    data.completed_tricks.last().map_or(PlayerName::User, |t| t.winner) == player_name
+    data.completed_tricks.last().map_or(data.leader, |t| t.winner) == player_name
 }
 
 /// Returns the [TrickNumber] for the current trick.